@@ -0,0 +1,246 @@
+//! `#[derive(FromLine)]` for the puzzle inputs in `advent_of_code_2021`, see
+//! `advent_of_code_2021::parse` for how patterns are written.
+
+use proc_macro::TokenStream;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, LitStr,
+    PathArguments, Type,
+};
+
+/// Derive `FromStr` from a `#[pattern("...")]` on the struct, or on each variant of an enum. The
+/// error is a `ParseError` pointing at the part of the line that didn't fit.
+#[proc_macro_derive(FromLine, attributes(pattern))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_line(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn from_line(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern(&input.attrs, name.span())?;
+            let build = build(&pattern, quote!(#name), &data.fields)?;
+            let literals = &pattern.literals;
+            quote! {
+                let fields = ::advent_of_code_2021::parse::match_pattern(s, &[#(#literals),*])?;
+                Ok(#build)
+            }
+        }
+        Data::Enum(data) => {
+            let mut variants = vec![];
+            let mut expected = vec![];
+            for variant in &data.variants {
+                let pattern = pattern(&variant.attrs, variant.ident.span())?;
+                let variant_name = &variant.ident;
+                let build = build(&pattern, quote!(#name::#variant_name), &variant.fields)?;
+                let literals = &pattern.literals;
+                variants.push(quote! {
+                    if let Ok(fields) = ::advent_of_code_2021::parse::match_pattern(s, &[#(#literals),*]) {
+                        return Ok(#build);
+                    }
+                });
+                expected.push(format!("{:?}", pattern.source));
+            }
+            let expected = format!("expected one of {}", expected.join(", "));
+            quote! {
+                #(#variants)*
+                Err(::advent_of_code_2021::parse::ParseError::at(s, s, #expected))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                name.span(),
+                "FromLine can't be derived for unions",
+            ));
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #type_generics #where_clause {
+            type Err = ::advent_of_code_2021::parse::ParseError;
+
+            #[allow(unused_variables)]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                #body
+            }
+        }
+    })
+}
+
+/// A pattern split into the literal text around each `{field}`, so there is always one more
+/// literal than there are fields
+struct Pattern {
+    source: String,
+    literals: Vec<String>,
+    fields: Vec<Placeholder>,
+}
+
+struct Placeholder {
+    /// A field name, or an index for tuple fields
+    name: String,
+    /// Set for `{field*}`, the separator between its values, or `None` for whitespace
+    repeated: Option<Option<String>>,
+}
+
+fn pattern(attrs: &[Attribute], span: Span) -> Result<Pattern, Error> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("pattern"))
+        .ok_or_else(|| Error::new(span, r#"expected a #[pattern("...")] attribute"#))?;
+    let source = attr.parse_args::<LitStr>()?;
+    parse_pattern(&source.value()).map_err(|message| Error::new(source.span(), message))
+}
+
+fn parse_pattern(source: &str) -> Result<Pattern, String> {
+    let mut literals = vec![String::new()];
+    let mut fields = vec![];
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literals.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literals.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                if !fields.is_empty() && literals.last().unwrap().is_empty() {
+                    return Err("fields must be separated by some text".to_string());
+                }
+
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err("unclosed {".to_string()),
+                    }
+                }
+                let (name, repeated) = match placeholder.split_once('*') {
+                    Some((name, "")) => (name, Some(None)),
+                    Some((name, separator)) => (name, Some(Some(separator.to_string()))),
+                    None => (placeholder.as_str(), None),
+                };
+                fields.push(Placeholder {
+                    name: name.trim().to_string(),
+                    repeated,
+                });
+                literals.push(String::new());
+            }
+            '}' => return Err("unmatched }, use }} for a literal }".to_string()),
+            c => literals.last_mut().unwrap().push(c),
+        }
+    }
+
+    Ok(Pattern {
+        source: source.to_string(),
+        literals,
+        fields,
+    })
+}
+
+/// Build `path` out of the matched `fields`, parsing each one into its type
+fn build(pattern: &Pattern, path: TokenStream2, fields: &Fields) -> Result<TokenStream2, Error> {
+    let members = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| (field.ident.clone().unwrap().to_string(), &field.ty))
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (i.to_string(), &field.ty))
+            .collect(),
+        Fields::Unit => vec![],
+    };
+
+    let mut values = vec![];
+    for (name, ty) in &members {
+        let index = pattern
+            .fields
+            .iter()
+            .position(|placeholder| &placeholder.name == name)
+            .ok_or_else(|| {
+                Error::new_spanned(ty, format!("{{{}}} is missing from the pattern", name))
+            })?;
+        values.push(parse_field(index, &pattern.fields[index], ty)?);
+    }
+    for placeholder in &pattern.fields {
+        let uses = pattern
+            .fields
+            .iter()
+            .filter(|other| other.name == placeholder.name)
+            .count();
+        if !members.iter().any(|(name, _)| name == &placeholder.name) || uses > 1 {
+            return Err(Error::new(
+                Span::call_site(),
+                format!(
+                    "{{{}}} in the pattern must match exactly one field",
+                    placeholder.name
+                ),
+            ));
+        }
+    }
+
+    Ok(match fields {
+        Fields::Named(_) => {
+            let names = members.iter().map(|(name, _)| format_ident!("{}", name));
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => path,
+    })
+}
+
+fn parse_field(index: usize, placeholder: &Placeholder, ty: &Type) -> Result<TokenStream2, Error> {
+    Ok(match &placeholder.repeated {
+        None => quote!(::advent_of_code_2021::parse::parse_at::<#ty>(s, fields[#index])?),
+        Some(separator) => {
+            let element = element_type(ty).ok_or_else(|| {
+                Error::new_spanned(ty, "repeated fields must be a Vec or an array")
+            })?;
+            let separator = match separator {
+                Some(separator) => quote!(Some(#separator)),
+                None => quote!(None),
+            };
+            quote! {
+                ::advent_of_code_2021::parse::parse_repeated::<#element, #ty>(
+                    s,
+                    fields[#index],
+                    #separator,
+                )?
+            }
+        }
+    })
+}
+
+/// The `T` in `Vec<T>` or `[T; N]`
+fn element_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Path(path) => {
+            let last = path.path.segments.last()?;
+            match &last.arguments {
+                PathArguments::AngleBracketed(arguments) if last.ident == "Vec" => {
+                    match arguments.args.first()? {
+                        GenericArgument::Type(element) => Some(element),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
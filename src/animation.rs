@@ -0,0 +1,352 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::coordinates::Grid;
+
+/// Where and how to save the frames recorded while solving
+#[derive(Debug, Clone)]
+pub struct RecordOptions {
+    /// Frames are written to `<day>.<part>.png`, or `<day>.png` when both parts are solved
+    /// together
+    pub dir: PathBuf,
+    /// How long each frame is shown for
+    pub delay: Duration,
+}
+
+/// An RGBA image, one frame of an animation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// A transparent frame
+    pub fn new(width: usize, height: usize) -> Frame {
+        Frame {
+            width,
+            height,
+            pixels: vec![0; width * height * 4],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Set a pixel, with `(0, 0)` the top left
+    pub fn set(&mut self, x: usize, y: usize, rgba: [u8; 4]) {
+        let offset = (y * self.width + x) * 4;
+        self.pixels[offset..offset + 4].copy_from_slice(&rgba);
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> [u8; 4] {
+        let offset = (y * self.width + x) * 4;
+        self.pixels[offset..offset + 4].try_into().unwrap()
+    }
+
+    /// Draw `patch` over `self` with its top left corner at `(x, y)`
+    fn paste(&mut self, x: usize, y: usize, patch: &Frame) {
+        for row in 0..patch.height {
+            let from = row * patch.width * 4;
+            let to = ((y + row) * self.width + x) * 4;
+            self.pixels[to..to + patch.width * 4]
+                .copy_from_slice(&patch.pixels[from..from + patch.width * 4]);
+        }
+    }
+
+    /// The smallest part of `self` that differs from `previous`, which is the same size, along
+    /// with where it goes. Identical frames give their top left pixel.
+    fn changed_since(&self, previous: &Frame) -> (usize, usize, Frame) {
+        let (mut x_min, mut y_min, mut x_max, mut y_max) = (self.width, self.height, 0, 0);
+        for y in 0..self.height {
+            let row = y * self.width * 4..(y + 1) * self.width * 4;
+            if self.pixels[row.clone()] == previous.pixels[row.clone()] {
+                continue;
+            }
+            let mut differs = self.pixels[row.clone()]
+                .chunks(4)
+                .zip(previous.pixels[row].chunks(4))
+                .map(|(a, b)| a != b);
+            if let Some(first) = differs.position(|differs| differs) {
+                let last = differs
+                    .rposition(|differs| differs)
+                    .map_or(first, |i| first + 1 + i);
+                x_min = x_min.min(first);
+                y_min = y_min.min(y);
+                x_max = x_max.max(last + 1);
+                y_max = y_max.max(y + 1);
+            }
+        }
+        if x_min >= x_max {
+            (x_min, y_min, x_max, y_max) = (0, 0, 1, 1);
+        }
+
+        let mut changed = Frame::new(x_max - x_min, y_max - y_min);
+        for y in y_min..y_max {
+            let from = (y * self.width + x_min) * 4;
+            let to = (y - y_min) * changed.width * 4;
+            changed.pixels[to..to + changed.width * 4]
+                .copy_from_slice(&self.pixels[from..from + changed.width * 4]);
+        }
+
+        (x_min, y_min, changed)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draw the grid as a frame with `y_min` as the top row, converting each cell to RGBA with
+    /// `converter` like `write_image`
+    pub fn frame<F>(&self, converter: F) -> Frame
+    where
+        F: Fn(&T) -> [u8; 4],
+    {
+        let mut frame = Frame::new(self.width(), self.height());
+        for (row, y) in self.y_range().enumerate() {
+            for (column, x) in self.x_range().enumerate() {
+                frame.set(column, row, converter(self.get(x, y)));
+            }
+        }
+
+        frame
+    }
+}
+
+#[derive(Debug)]
+pub enum AnimationError {
+    Png(String),
+    Io { path: String, error: io::Error },
+}
+
+impl Display for AnimationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationError::Png(message) => write!(f, "couldn't encode the animation: {}", message),
+            AnimationError::Io { path, error } => {
+                write!(f, "couldn't write animation {}: {}", path, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+impl From<png::EncodingError> for AnimationError {
+    fn from(e: png::EncodingError) -> Self {
+        AnimationError::Png(e.to_string())
+    }
+}
+
+/// Collects the frames a part pushes so we can watch it run. Recorders that aren't recording
+/// ignore everything pushed to them without building the frame.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    frames: Option<Arc<Mutex<Frames>>>,
+}
+
+/// The frames pushed so far, each kept as the part that changed since the one before, as a lot of
+/// mostly unchanged frames can add up. They're only encoded once they're written.
+#[derive(Debug, Default)]
+struct Frames {
+    changes: Vec<Change>,
+    /// The whole of the last frame, to compare the next one against
+    last: Option<Frame>,
+}
+
+/// A `width` by `height` frame, made by drawing `patch` at `(x, y)` over the frame before it.
+/// A frame that's a different size to the one before is drawn over a transparent frame.
+#[derive(Debug)]
+struct Change {
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    patch: Frame,
+}
+
+impl Recorder {
+    /// A recorder that keeps its frames, `Recorder::default()` is one that doesn't
+    pub fn new() -> Recorder {
+        Recorder {
+            frames: Some(Arc::new(Mutex::new(Frames::default()))),
+        }
+    }
+
+    #[inline]
+    pub fn is_recording(&self) -> bool {
+        self.frames.is_some()
+    }
+
+    /// Push a snapshot of `grid`, see `Grid::frame`
+    #[inline]
+    pub fn grid<T, F>(&self, grid: &Grid<T>, converter: F)
+    where
+        F: Fn(&T) -> [u8; 4],
+    {
+        self.frame(|| grid.frame(converter));
+    }
+
+    /// Push the frame built by `build`, which is only called while recording
+    #[inline]
+    pub fn frame<F: FnOnce() -> Frame>(&self, build: F) {
+        if let Some(frames) = &self.frames {
+            let frame = build();
+            if frame.width > 0 && frame.height > 0 {
+                let mut frames = frames.lock().unwrap();
+                let (x, y, patch) = match &frames.last {
+                    Some(last) if (last.width, last.height) == (frame.width, frame.height) => {
+                        frame.changed_since(last)
+                    }
+                    _ => (0, 0, frame.clone()),
+                };
+                frames.changes.push(Change {
+                    width: frame.width,
+                    height: frame.height,
+                    x,
+                    y,
+                    patch,
+                });
+                frames.last = Some(frame);
+            }
+        }
+    }
+
+    /// How many frames have been pushed so far
+    pub fn len(&self) -> usize {
+        self.frames
+            .as_ref()
+            .map_or(0, |frames| frames.lock().unwrap().changes.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the frames to `path` as an animated PNG, showing each for `delay`. Frames smaller
+    /// than the largest are drawn in its top left corner.
+    pub fn write_png(&self, path: &Path, delay: Duration) -> Result<(), AnimationError> {
+        let frames = match &self.frames {
+            Some(frames) => frames.lock().unwrap(),
+            None => return Ok(()),
+        };
+        let changes = &frames.changes;
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let width = changes.iter().map(|change| change.width).max().unwrap();
+        let height = changes.iter().map(|change| change.height).max().unwrap();
+
+        let io_error = |error| AnimationError::Io {
+            path: path.display().to_string(),
+            error,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let file = File::create(path).map_err(io_error)?;
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        // play forever
+        encoder.set_animated(changes.len() as u32, 0)?;
+        encoder.set_frame_delay(delay.as_millis().min(u16::MAX as u128) as u16, 1000)?;
+        encoder.set_blend_op(png::BlendOp::Source)?;
+        let mut writer = encoder.write_header()?;
+
+        // each frame only covers what changed since the last one, drawn over the top of it
+        let mut frame = Frame::new(0, 0);
+        let mut previous: Option<Frame> = None;
+        for change in changes {
+            if (frame.width, frame.height) != (change.width, change.height) {
+                frame = Frame::new(change.width, change.height);
+            }
+            frame.paste(change.x, change.y, &change.patch);
+            let mut canvas = Frame::new(width, height);
+            canvas.paste(0, 0, &frame);
+
+            let (x, y, changed) = match &previous {
+                Some(previous) => canvas.changed_since(previous),
+                None => (0, 0, canvas.clone()),
+            };
+            writer.reset_frame_position()?;
+            writer.set_frame_dimension(changed.width as u32, changed.height as u32)?;
+            writer.set_frame_position(x as u32, y as u32)?;
+            writer.write_image_data(&changed.pixels)?;
+            previous = Some(canvas);
+        }
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn records_frames() {
+        let off = Recorder::default();
+        off.frame(|| panic!("frames shouldn't be built while not recording"));
+        assert!(!off.is_recording());
+        assert!(off.is_empty());
+
+        let recorder = Recorder::new();
+        let mut grid: Grid<bool> = Grid::new_from_range(0..3, 0..2);
+        grid.set(2, 1, true);
+        let on = |&on: &bool| if on { [255; 4] } else { [0, 0, 0, 255] };
+        recorder.grid(&grid, on);
+        let frame = grid.frame(on);
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.get(2, 1), [255; 4]);
+        assert_eq!(frame.get(0, 0), [0, 0, 0, 255]);
+
+        recorder.frame(|| Frame::new(1, 4));
+        let mut dot = Frame::new(1, 4);
+        dot.set(0, 2, [255; 4]);
+        recorder.frame(|| dot.clone());
+        assert_eq!(recorder.len(), 3);
+        {
+            let frames = recorder.frames.as_ref().unwrap().lock().unwrap();
+            assert_eq!(frames.changes[0].patch, frame);
+            // only the pixel that changed is kept
+            let change = &frames.changes[2];
+            assert_eq!((change.x, change.y), (0, 2));
+            assert_eq!((change.patch.width, change.patch.height), (1, 1));
+            assert_eq!(frames.last, Some(dot));
+        }
+
+        let dir = TempDir::new("frames");
+        let path = dir.join("frames.png");
+        recorder
+            .write_png(&path, Duration::from_millis(100))
+            .unwrap();
+
+        let mut reader = png::Decoder::new(File::open(&path).unwrap())
+            .read_info()
+            .unwrap();
+        assert_eq!(reader.info().size(), (3, 4));
+        assert_eq!(reader.info().animation_control().unwrap().num_frames, 3);
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels[(3 + 2) * 4..(3 + 3) * 4], [255; 4]);
+        // padded out to the size of the largest frame
+        assert_eq!(pixels[(3 * 3) * 4..], [0; 12]);
+
+        // only the rows the first frame filled in changed
+        let next = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((next.width, next.height), (3, 2));
+        assert_eq!(pixels[..3 * 2 * 4], [0; 24]);
+    }
+}
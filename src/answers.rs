@@ -0,0 +1,193 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::sync::Mutex;
+
+use toml::{Table, Value};
+
+use crate::input::normalise::normalise;
+use crate::problem::Answer;
+use crate::toml_file::{TomlFile, TomlFileError};
+
+pub static DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// How an answer compares to the one we've recorded for the same input
+#[derive(Debug, Clone, PartialEq)]
+pub enum Check {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// Nothing has been recorded for this input yet
+    Unknown,
+    /// Nothing had been recorded, so this answer was just saved
+    Recorded,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Correct => write!(f, "correct"),
+            Check::Wrong { .. } => write!(f, "wrong"),
+            Check::Unknown => write!(f, "unknown"),
+            Check::Recorded => write!(f, "recorded"),
+        }
+    }
+}
+
+/// Known good answers, stored as TOML with a table per day and part keyed by a hash of the input:
+///
+/// ```toml
+/// [8.1]
+/// 5d0b2f6c1e0a9a41 = "365"
+/// ```
+pub struct Answers {
+    file: TomlFile,
+    known: Mutex<Table>,
+    record: bool,
+}
+
+impl Answers {
+    /// Load the answers at `path`, starting with none if the file doesn't exist yet
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers, TomlFileError> {
+        let file = TomlFile::new("answers", path.as_ref());
+        let known = file.load()?;
+
+        Ok(Answers {
+            file,
+            known: Mutex::new(known),
+            record: false,
+        })
+    }
+
+    /// Save any answer we don't know yet when it's checked
+    pub fn with_recording(mut self, record: bool) -> Self {
+        self.record = record;
+        self
+    }
+
+    pub fn get(&self, day: usize, part: usize, input: &str) -> Option<String> {
+        let known = self.known.lock().unwrap();
+        known
+            .get(&day.to_string())
+            .and_then(|parts| parts.get(part.to_string()))
+            .and_then(|hashes| hashes.get(input_hash(input)))
+            .and_then(|answer| answer.as_str())
+            .map(String::from)
+    }
+
+    pub fn check(
+        &self,
+        day: usize,
+        part: usize,
+        input: &str,
+        answer: &Answer,
+    ) -> Result<Check, TomlFileError> {
+        Ok(match self.get(day, part, input) {
+            Some(expected) if answer.matches(&expected) => Check::Correct,
+            Some(expected) => Check::Wrong { expected },
+            // there's nothing to record until the part finds an answer
+            None if answer.value().is_none() => Check::Unknown,
+            None if self.record => {
+                self.record(day, part, input, &answer.to_string())?;
+                Check::Recorded
+            }
+            None => Check::Unknown,
+        })
+    }
+
+    /// Save `answer` as the correct one for `input`, replacing anything recorded before
+    pub fn record(
+        &self,
+        day: usize,
+        part: usize,
+        input: &str,
+        answer: &str,
+    ) -> Result<(), TomlFileError> {
+        let mut known = self.known.lock().unwrap();
+        let hashes =
+            [day.to_string(), part.to_string()]
+                .into_iter()
+                .fold(&mut *known, |table, key| {
+                    table
+                        .entry(key)
+                        .or_insert_with(|| Value::Table(Table::new()))
+                        .as_table_mut()
+                        .expect("answers are nested in tables")
+                });
+        hashes.insert(input_hash(input), Value::String(answer.to_string()));
+
+        self.file.save(&known)
+    }
+}
+
+/// A 64 bit FNV-1a hash, which unlike `DefaultHasher` is stable between Rust versions. Inputs are
+/// hashed fully normalised whatever `--keep-trailing-lines` says, so the same input always has the
+/// same answers however it was saved.
+pub fn input_hash(input: &str) -> String {
+    let (input, _) = normalise(input, true);
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn check_and_record() {
+        let dir = TempDir::new("answers");
+        let path = dir.join("answers.toml");
+
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(
+            answers.check(8, 1, "input", &Answer::from(365)).unwrap(),
+            Check::Unknown
+        );
+        assert!(!path.exists());
+
+        let answers = answers.with_recording(true);
+        assert_eq!(
+            answers.check(8, 1, "input", &Answer::NoAnswer).unwrap(),
+            Check::Unknown
+        );
+        assert!(!path.exists());
+        assert_eq!(
+            answers.check(8, 1, "input", &Answer::from(365)).unwrap(),
+            Check::Recorded
+        );
+        assert_eq!(
+            answers.check(8, 1, "input", &Answer::from(365)).unwrap(),
+            Check::Correct
+        );
+        assert_eq!(
+            answers.check(8, 2, "input", &Answer::from(1)).unwrap(),
+            Check::Recorded
+        );
+        assert_eq!(
+            answers.check(8, 1, "other", &Answer::from(1)).unwrap(),
+            Check::Recorded
+        );
+
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(
+            answers.check(8, 1, "input", &Answer::from(366)).unwrap(),
+            Check::Wrong {
+                expected: "365".into()
+            }
+        );
+        assert_eq!(
+            answers.check(8, 2, "input", &Answer::from(1)).unwrap(),
+            Check::Correct
+        );
+        assert_eq!(
+            answers
+                .check(8, 2, "input\r\n\r\n", &Answer::from(1))
+                .unwrap(),
+            Check::Correct
+        );
+    }
+}
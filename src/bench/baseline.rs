@@ -0,0 +1,166 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use toml::{Table, Value};
+
+use crate::bench::Stats;
+use crate::toml_file::{TomlFile, TomlFileError};
+
+pub static DEFAULT_BASELINE_FILE: &str = "baseline.toml";
+
+/// Flag parts that got more than 25% slower by default, anything less is lost in the noise of
+/// parts that only take microseconds
+pub static DEFAULT_THRESHOLD: f64 = 0.25;
+
+/// How a part's median compares to its baseline median
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub median: Duration,
+    /// Slower by more than the threshold, with every sample slower than the baseline
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// The relative change from the baseline, positive when we got slower
+    pub fn change(&self) -> f64 {
+        self.median.as_secs_f64() / self.baseline.as_secs_f64() - 1.0
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+.1}% vs baseline", self.change() * 100.0)?;
+        if self.regressed {
+            write!(f, ", regressed")?;
+        }
+        Ok(())
+    }
+}
+
+/// Median runtimes from a reference run, stored as TOML with a table per day holding the
+/// nanoseconds for each part:
+///
+/// ```toml
+/// [8]
+/// 1 = 3911
+/// 2 = 314435
+/// ```
+pub struct Baseline {
+    file: TomlFile,
+    medians: Mutex<Table>,
+    threshold: f64,
+    save: bool,
+}
+
+impl Baseline {
+    /// Load the baseline at `path`, starting empty if the file doesn't exist yet
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Baseline, TomlFileError> {
+        let file = TomlFile::new("baseline", path.as_ref());
+        let medians = file.load()?;
+
+        Ok(Baseline {
+            file,
+            medians: Mutex::new(medians),
+            threshold: DEFAULT_THRESHOLD,
+            save: false,
+        })
+    }
+
+    /// How much slower, as a fraction of the baseline, a part can get before it's flagged
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Replace the baseline with every median we compare
+    pub fn with_save(mut self, save: bool) -> Self {
+        self.save = save;
+        self
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<Duration> {
+        let medians = self.medians.lock().unwrap();
+        medians
+            .get(&day.to_string())
+            .and_then(|parts| parts.get(part.to_string()))
+            .and_then(|nanos| nanos.as_integer())
+            .map(|nanos| Duration::from_nanos(nanos as u64))
+    }
+
+    /// Compare the median of `stats` against the baseline, then save it if we're saving a new
+    /// baseline. A slower median only counts as a regression if even the fastest sample was slower
+    /// than the baseline, otherwise the samples are too spread out to tell.
+    pub fn compare(
+        &self,
+        day: usize,
+        part: usize,
+        stats: &Stats,
+    ) -> Result<Option<Comparison>, TomlFileError> {
+        let median = stats.median;
+        let comparison = self.get(day, part).map(|baseline| Comparison {
+            baseline,
+            median,
+            regressed: median.as_secs_f64() > baseline.as_secs_f64() * (1.0 + self.threshold)
+                && stats.min > baseline,
+        });
+
+        if self.save {
+            self.record(day, part, median)?;
+        }
+
+        Ok(comparison)
+    }
+
+    fn record(&self, day: usize, part: usize, median: Duration) -> Result<(), TomlFileError> {
+        let mut medians = self.medians.lock().unwrap();
+        medians
+            .entry(day.to_string())
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("medians are nested in a table per day")
+            .insert(part.to_string(), Value::Integer(median.as_nanos() as i64));
+
+        self.file.save(&medians)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn compare_and_save() {
+        let dir = TempDir::new("baseline");
+        let path = dir.join("baseline.toml");
+        let ms = Duration::from_millis;
+        let stats = |samples: &[u64]| Stats::from_samples(samples.iter().map(|&s| ms(s)).collect());
+
+        let baseline = Baseline::load(&path).unwrap().with_save(true);
+        assert_eq!(baseline.compare(8, 1, &stats(&[100])).unwrap(), None);
+
+        let baseline = Baseline::load(&path).unwrap().with_threshold(0.2);
+        let slower = baseline
+            .compare(8, 1, &stats(&[140, 150, 160]))
+            .unwrap()
+            .unwrap();
+        assert!(slower.regressed);
+        assert_eq!(slower.to_string(), "+50.0% vs baseline, regressed");
+
+        let noisy = baseline
+            .compare(8, 1, &stats(&[90, 150, 160]))
+            .unwrap()
+            .unwrap();
+        assert!(!noisy.regressed);
+
+        let within = baseline.compare(8, 1, &stats(&[110])).unwrap().unwrap();
+        assert!(!within.regressed);
+
+        let faster = baseline.compare(8, 1, &stats(&[75])).unwrap().unwrap();
+        assert_eq!(faster.to_string(), "-25.0% vs baseline");
+        assert_eq!(baseline.get(8, 1), Some(ms(100)));
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+pub mod baseline;
+
+/// Never keep more than this many samples, even if the time budget would allow it
+pub static MAX_SAMPLES: usize = 100_000;
+
+/// How to sample a benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    /// Untimed runs before we start sampling
+    pub warmup: usize,
+    /// Take exactly this many samples, otherwise sample until `budget` runs out
+    pub samples: Option<usize>,
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            samples: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Summary statistics over a set of timing samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "Can't build stats without samples");
+        samples.sort_unstable();
+
+        let count = samples.len();
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / count as f64;
+        let variance = if count > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        // nearest rank percentile
+        let p95_rank = ((count as f64) * 0.95).ceil() as usize;
+
+        Stats {
+            samples: count,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95: samples[p95_rank.max(1) - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {} median {} mean {} ± {} p95 {} ({} samples)",
+            seconds(self.min),
+            seconds(self.median),
+            seconds(self.mean),
+            seconds(self.stddev),
+            seconds(self.p95),
+            self.samples
+        )
+    }
+}
+
+pub(crate) fn seconds(duration: Duration) -> String {
+    format!("{}.{:09}s", duration.as_secs(), duration.subsec_nanos())
+}
+
+/// Repeatedly time `runner`, returning the result of the last run along with its stats
+pub fn measure<T, F>(options: &BenchOptions, mut runner: F) -> (T, Stats)
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..options.warmup {
+        runner();
+    }
+
+    let target = options.samples.unwrap_or(MAX_SAMPLES).clamp(1, MAX_SAMPLES);
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    let mut result;
+    loop {
+        let now = Instant::now();
+        result = runner();
+        samples.push(now.elapsed());
+
+        let out_of_time = options.samples.is_none() && started.elapsed() >= options.budget;
+        if samples.len() >= target || out_of_time {
+            break;
+        }
+    }
+
+    (result, Stats::from_samples(samples))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples((1..=20).rev().map(Duration::from_millis).collect());
+
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.stddev.as_micros(), 5_916);
+
+        let (result, stats) = measure(
+            &BenchOptions {
+                warmup: 2,
+                samples: Some(5),
+                budget: Duration::ZERO,
+            },
+            || 7,
+        );
+        assert_eq!(result, 7);
+        assert_eq!(stats.samples, 5);
+    }
+}
@@ -0,0 +1,386 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use env_logger::Env;
+
+use advent_of_code_2021::animation::RecordOptions;
+use advent_of_code_2021::answers::{Answers, DEFAULT_ANSWERS_FILE};
+use advent_of_code_2021::bench::baseline::{Baseline, DEFAULT_BASELINE_FILE};
+use advent_of_code_2021::bench::BenchOptions;
+use advent_of_code_2021::days;
+use advent_of_code_2021::days::Day;
+use advent_of_code_2021::input::download::{Downloader, DEFAULT_BASE_URL, SESSION_ENV};
+use advent_of_code_2021::input::{InputSource, DEFAULT_INPUTS_DIR};
+use advent_of_code_2021::problem::extra::{ExtraArgs, DEFAULT_CONFIG_FILE};
+use advent_of_code_2021::problem::{RunFor, RunOptions};
+use advent_of_code_2021::report::summary::Summary;
+use advent_of_code_2021::report::Format;
+use advent_of_code_2021::scaffold::{Scaffold, DEFAULT_DAYS_DIR};
+use advent_of_code_2021::submit::{Submitter, DEFAULT_HISTORY_FILE};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the examples and problem for one or more days
+    Run {
+        #[command(flatten)]
+        run: RunArgs,
+        /// Submit the answers for the real inputs, skipping any known wrong answers
+        #[arg(long, requires = "session")]
+        submit: bool,
+        /// Where to keep the history of submitted answers
+        #[arg(long, default_value = DEFAULT_HISTORY_FILE)]
+        history: PathBuf,
+        /// How many days to run at the same time, results are still reported in order
+        #[arg(long, short, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Repeatedly time the parse and parts of one or more days against their real inputs
+    Bench {
+        #[command(flatten)]
+        run: RunArgs,
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Take exactly this many samples instead of sampling for --time
+        #[arg(long)]
+        samples: Option<usize>,
+        /// How long to sample each parse and part for, like `500ms`, `2s` or `1m`
+        #[arg(long, default_value = "1s", value_parser = parse_duration)]
+        time: Duration,
+        /// Median part times from a reference run to compare against
+        #[arg(long, default_value = DEFAULT_BASELINE_FILE)]
+        baseline: PathBuf,
+        /// Replace the baseline's times with this run's
+        #[arg(long)]
+        save_baseline: bool,
+        /// Flag parts that got slower than the baseline by more than this, like `10%`
+        #[arg(long, default_value = "25%", value_parser = parse_percent)]
+        threshold: f64,
+    },
+    /// Run one or more days and save the answers for any inputs without a known answer. Known
+    /// answers that no longer match are left for you to fix by hand.
+    Record {
+        #[command(flatten)]
+        run: RunArgs,
+    },
+    /// List the implemented days and which of their parts are solved
+    List,
+    /// Generate a new day from a template, register it and add an empty input for it
+    New {
+        /// The day to create, which mustn't exist yet
+        day: usize,
+        /// Directory holding the day modules and the `mod.rs` that registers them
+        #[arg(long, default_value = DEFAULT_DAYS_DIR)]
+        days_dir: PathBuf,
+        /// Directory holding a `<day>.txt` input for each day
+        #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+        inputs: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// A day (`9`), a range of days (`5..10`, `5..=10`) or `all`
+    days: Days,
+    /// Which part to run: 1, 2 or both
+    #[arg(long, default_value = "both")]
+    part: RunFor,
+    /// Directory holding a `<day>.txt` input for each day
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs: PathBuf,
+    /// Read a single day's input from this file instead, or `-` for stdin
+    #[arg(long, conflicts_with = "inputs")]
+    input: Option<PathBuf>,
+    /// Session cookie used to download any inputs missing from the inputs directory
+    #[arg(long, env = SESSION_ENV, hide_env_values = true)]
+    session: Option<String>,
+    /// Where to download inputs from and submit answers to
+    #[arg(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+    /// How to write results: console, json, junit or markdown
+    #[arg(long, default_value = "console")]
+    format: Format,
+    /// Give up on any part that takes longer than this, like `500ms`, `10s` or `1m`. When
+    /// benchmarking it's the limit for each sample.
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+    /// Known answers to check the real inputs against
+    #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+    /// Settings for each day, in a `[<day>]` table of `name = value`s
+    #[arg(long, default_value = DEFAULT_CONFIG_FILE)]
+    config: PathBuf,
+    /// Leave any blank lines at the end of the inputs for the parsers to deal with
+    #[arg(long)]
+    keep_trailing_lines: bool,
+    /// Save the frames each part records as an animated PNG in this directory, for days that
+    /// draw their progress
+    #[arg(long)]
+    frames: Option<PathBuf>,
+    /// How long to show each recorded frame for, like `100ms` or `1s`
+    #[arg(long, requires = "frames", default_value = "100ms", value_parser = parse_duration)]
+    frame_delay: Duration,
+    /// Settings for a single day, like `--days 1000` for day 6. These go last, after any of our
+    /// own options.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    settings: Vec<String>,
+}
+
+impl RunArgs {
+    fn selected_days(&self) -> Vec<&'static Day> {
+        let selected = days::DAYS
+            .iter()
+            .filter(|day| self.days.contains(day.number))
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            exit("No implemented days were selected");
+        }
+        if self.input.is_some() && selected.len() > 1 {
+            exit("--input can only be used when running a single day");
+        }
+
+        selected
+    }
+
+    fn options(&self, selected: &[&'static Day]) -> RunOptions {
+        let input = match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None => match &self.session {
+                Some(session) => InputSource::Download {
+                    dir: self.inputs.clone(),
+                    downloader: Downloader::new(session).with_base_url(&self.base_url),
+                },
+                None => InputSource::Directory(self.inputs.clone()),
+            },
+        };
+
+        RunOptions {
+            run_for: self.part,
+            input,
+            reporter: self.format.reporter(),
+            answers: Some(self.answers(false)),
+            timeout: self.timeout,
+            extra_args: Some(self.extra_args(selected)),
+            frames: self.frames.as_ref().map(|dir| RecordOptions {
+                dir: dir.clone(),
+                delay: self.frame_delay,
+            }),
+            trim_trailing_lines: !self.keep_trailing_lines,
+            ..Default::default()
+        }
+    }
+
+    /// The settings from the config file, with any from the command line for the selected day.
+    /// Command line settings are parsed for a single day's problem, so they can't be given for more.
+    fn extra_args(&self, selected: &[&'static Day]) -> Arc<ExtraArgs> {
+        let extra_args = match ExtraArgs::load(&self.config) {
+            Ok(extra_args) => extra_args,
+            Err(e) => exit(&e.to_string()),
+        };
+
+        Arc::new(match selected {
+            [day] => extra_args.with_args(day.number, &self.settings),
+            _ if !self.settings.is_empty() => {
+                exit("Settings can only be given on the command line when running a single day")
+            }
+            _ => extra_args,
+        })
+    }
+
+    fn answers(&self, record: bool) -> Arc<Answers> {
+        match Answers::load(&self.answers) {
+            Ok(answers) => Arc::new(answers.with_recording(record)),
+            Err(e) => exit(&e.to_string()),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Days {
+    All,
+    Range(RangeInclusive<usize>),
+}
+
+impl Days {
+    fn contains(&self, day: usize) -> bool {
+        match self {
+            Days::All => true,
+            Days::Range(range) => range.contains(&day),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<usize>()
+                .map_err(|e| format!("bad day {:?}: {}", day, e))
+        };
+
+        if s == "all" {
+            Ok(Days::All)
+        } else if let Some((start, end)) = s.split_once("..=") {
+            Ok(Days::Range(parse_day(start)?..=parse_day(end)?))
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse_day(end)?;
+            if end == 0 {
+                return Err(format!("empty range: {}", s));
+            }
+            Ok(Days::Range(parse_day(start)?..=end - 1))
+        } else {
+            let day = parse_day(s)?;
+            Ok(Days::Range(day..=day))
+        }
+    }
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|e| format!("bad duration {:?}: {}", s, e))?;
+
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown duration unit {:?}, use ms, s or m", unit)),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| format!("bad duration {:?}: {}", s, e))
+}
+
+/// Run every selected day, then summarise how they went. We exit with an error if any part failed
+/// or got slower than the baseline.
+fn run_days(selected: Vec<&'static Day>, mut options: RunOptions, jobs: usize) {
+    let summary = Arc::new(Summary::new(options.reporter.clone()));
+    options.reporter = summary.clone();
+
+    days::run_all(&selected, &options, jobs);
+    options.reporter.finish();
+
+    eprintln!("{}", summary);
+    if !summary.failures().is_empty() || !summary.regressions().is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn parse_percent(s: &str) -> Result<f64, String> {
+    let percent = s
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .map_err(|e| format!("bad percentage {:?}: {}", s, e))?;
+    if percent.is_nan() || percent < 0.0 {
+        return Err(format!("bad percentage {:?}: it must be 0% or more", s));
+    }
+
+    Ok(percent / 100.0)
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
+
+fn main() {
+    env_logger::init_from_env(Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "warn"));
+
+    match Cli::parse().command {
+        Command::Run {
+            run,
+            submit,
+            history,
+            jobs,
+        } => {
+            let selected = run.selected_days();
+            let mut options = run.options(&selected);
+            if submit {
+                options.submitter = run.session.as_ref().map(|session| {
+                    Submitter::new(session)
+                        .with_base_url(&run.base_url)
+                        .with_history(history)
+                });
+            }
+
+            run_days(selected, options, jobs);
+        }
+        Command::Bench {
+            run,
+            warmup,
+            samples,
+            time,
+            baseline,
+            save_baseline,
+            threshold,
+        } => {
+            let selected = run.selected_days();
+            let mut options = run.options(&selected);
+            options.bench = Some(BenchOptions {
+                warmup,
+                samples,
+                budget: time,
+            });
+            options.baseline = match Baseline::load(&baseline) {
+                Ok(baseline) => Some(Arc::new(
+                    baseline.with_threshold(threshold).with_save(save_baseline),
+                )),
+                Err(e) => exit(&e.to_string()),
+            };
+
+            run_days(selected, options, 1);
+        }
+        Command::Record { run } => {
+            let selected = run.selected_days();
+            let mut options = run.options(&selected);
+            options.answers = Some(run.answers(true));
+
+            run_days(selected, options, 1);
+        }
+        Command::List => {
+            for day in days::DAYS {
+                let parts = day
+                    .parts
+                    .parts()
+                    .iter()
+                    .map(|part| part.to_string())
+                    .collect::<Vec<_>>();
+                match parts.len() {
+                    0 => println!("Day {:2}: not solved yet", day.number),
+                    1 => println!("Day {:2}: part {}", day.number, parts[0]),
+                    _ => println!("Day {:2}: parts {}", day.number, parts.join(", ")),
+                }
+            }
+        }
+        Command::New {
+            day,
+            days_dir,
+            inputs,
+        } => match Scaffold::new(days_dir).with_inputs_dir(inputs).create(day) {
+            Ok(written) => {
+                for path in written {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => exit(&e.to_string()),
+        },
+    }
+}
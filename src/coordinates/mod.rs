@@ -95,7 +95,7 @@ impl<T> Grid<T> {
 }
 
 impl<T: Default> Grid<T> {
-    pub fn enumerate(&self) -> GridEnumerator<T> {
+    pub fn enumerate(&self) -> GridEnumerator<'_, T> {
         GridEnumerator {
             grid: self,
             indices: self.indices(),
@@ -138,7 +138,7 @@ impl<T: Clone + Default> Grid<T> {
         let mut raw_y = self.raw_y(y);
 
         if raw_y < 0 {
-            let mut to_prepend = vec![vec![Default::default(); 1]; raw_y.abs() as usize];
+            let mut to_prepend = vec![vec![Default::default(); 1]; raw_y.unsigned_abs()];
             to_prepend.append(&mut self.grid);
             self.grid = to_prepend;
 
@@ -154,7 +154,7 @@ impl<T: Clone + Default> Grid<T> {
         let y_index = raw_y as usize;
 
         if raw_x < 0 {
-            let new_columns = raw_x.abs() as usize;
+            let new_columns = raw_x.unsigned_abs();
             for i in 0..self.grid.len() {
                 let mut to_prepend = vec![Default::default(); new_columns];
 
@@ -192,16 +192,16 @@ impl<T: Clone + Default> Grid<T> {
         let w = &mut BufWriter::new(file);
         let mut encoder = png::Encoder::new(w, self.width() as u32, self.height() as u32);
 
-        encoder.set_color(png::ColorType::RGBA);
+        encoder.set_color(png::ColorType::Rgba);
         encoder.set_depth(png::BitDepth::Eight);
         let mut writer = encoder.write_header().unwrap();
-        let mut stream_writer = writer.stream_writer();
+        let mut stream_writer = writer.stream_writer().unwrap();
 
         let mut buffer = vec![0; self.width() * 4];
         for y in (self.y_min()..self.y_max()).rev() {
             for x in self.x_min()..self.x_max() {
                 let offset: usize = (self.raw_x(x) as usize) * 4;
-                let rgba = converter(self.get(x as isize, y as isize));
+                let rgba = converter(self.get(x, y));
                 buffer.splice(offset..offset + 4, rgba.iter().copied());
             }
 
@@ -216,7 +216,7 @@ impl<T: fmt::Display + Default> Grid<T> {
     pub fn print_bottom_up(&self) {
         for y in (self.y_min()..self.y_max()).rev() {
             for x in self.x_min()..self.x_max() {
-                print!("{}", self.get(x as isize, y as isize));
+                print!("{}", self.get(x, y));
             }
             println!();
         }
@@ -225,7 +225,7 @@ impl<T: fmt::Display + Default> Grid<T> {
     pub fn print_top_down(&self) {
         for y in self.y_min()..self.y_max() {
             for x in self.x_min()..self.x_max() {
-                print!("{}", self.get(x as isize, y as isize));
+                print!("{}", self.get(x, y));
             }
             println!();
         }
@@ -0,0 +1,88 @@
+use clap::Args;
+
+use crate::configurable;
+use crate::parse::{self, parse_at, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+
+pub(crate) struct One {}
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub(crate) struct Window {
+    /// How many depths part 2 sums together before comparing them
+    #[arg(long = "window", default_value_t = 3, value_parser = at_least_one)]
+    size: usize,
+}
+
+configurable!(Window);
+
+fn at_least_one(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("the window can't be empty".to_string()),
+        Ok(size) => Ok(size),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+impl Problem for One {
+    type Input = Vec<usize>;
+    type Extra = Window;
+
+    const DAY: usize = 1;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |depth| parse_at(s, depth))
+    }
+
+    fn part_1(depths: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        let increases = depths.windows(2).filter(|pair| pair[1] > pair[0]).count();
+
+        Answer::from(increases)
+    }
+
+    fn part_2(depths: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        // there's nothing to compare if not even one window fits
+        if state.extra.size == 0 || state.extra.size > depths.len() {
+            return Answer::NoAnswer;
+        }
+
+        let windows = depths
+            .windows(state.extra.size)
+            .map(|window| window.iter().sum())
+            .collect();
+
+        Self::part_1(&windows, state)
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"199
+200
+208
+210
+200
+207
+240
+269
+260
+263"#,
+            "7",
+            "5",
+        )]
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<One>(options, Window::default());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_tests;
+
+    problem_tests!(One, Window::default();
+        example_1[part_1, part_2];
+        part_1 => "1583",
+        part_2 => "1627"
+    );
+}
@@ -1,10 +1,5 @@
-use env_logger::Env;
-
-use advent_of_code_2021::example;
-use advent_of_code_2021::problem::RunFor;
-use advent_of_code_2021::problem::{run, Problem, ProblemState};
-
-use std::default::Default;
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
 
 trait Deliminator {
     fn is_open(&self) -> bool;
@@ -32,30 +27,35 @@ impl Deliminator for char {
     }
 }
 
-struct Ten {}
+pub(crate) struct Ten {}
 
 impl Problem for Ten {
     type Input = Vec<Result<Vec<char>, char>>;
     type Extra = ();
 
-    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Self::Input {
-        s.split('\n')
-            .map(|line| {
-                let mut stack = vec![];
-                for c in line.chars() {
-                    if c.is_open() {
-                        stack.push(c);
-                    } else if stack.pop().unwrap().pair() != c {
-                        return Err(c);
+    const DAY: usize = 10;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |line| {
+            let mut stack = vec![];
+            for (i, c) in line.char_indices() {
+                if c.is_open() {
+                    stack.push(c);
+                } else {
+                    let open = stack.pop().ok_or_else(|| {
+                        ParseError::at(s, &line[i..i + c.len_utf8()], "unopened chunk")
+                    })?;
+                    if open.pair() != c {
+                        return Ok(Err(c));
                     }
                 }
+            }
 
-                Ok(stack)
-            })
-            .collect()
+            Ok(Ok(stack))
+        })
     }
 
-    fn part_1(syntax_stacks: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_1(syntax_stacks: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let error_score = syntax_stacks
             .iter()
             .filter_map(|result| {
@@ -73,10 +73,10 @@ impl Problem for Ten {
             })
             .sum::<usize>();
 
-        Some(format!("{}", error_score))
+        Answer::from(error_score)
     }
 
-    fn part_2(syntax_stacks: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_2(syntax_stacks: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let mut completion_scores = syntax_stacks
             .iter()
             .cloned()
@@ -103,18 +103,12 @@ impl Problem for Ten {
         completion_scores.sort_unstable();
         let middle_score = completion_scores[completion_scores.len() / 2];
 
-        Some(format!("{}", middle_score))
+        Answer::from(middle_score)
     }
 
-    fn problem_number() -> usize {
-        10
-    }
-}
-
-fn main() {
-    env_logger::init_from_env(Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "warn"));
-
-    example!(Ten; RunFor::Both, (), r#"[({(<(())[]>[[{[]{<()<>>
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
 {([(<{}[<>[]}>{[]{[(<()>
 (((({<>}<{<{<>}{[]{[]{}
@@ -123,18 +117,26 @@ fn main() {
 {<[[]]>}<{[{[{[]{()[[[]
 [<(<(<(<{}))><([]([]()
 <{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]"#);
-    run::<Ten>((), include_str!("10_input.txt"));
+<{([{{}}[<[[[<>{}]]]>[]]"#,
+            "26397",
+            "288957",
+        )]
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Ten>(options, ());
 }
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_2021::problem::assert_solution;
+    use crate::problem_tests;
 
     use super::*;
 
-    #[test]
-    fn test() {
-        assert_solution::<Ten>(include_str!("10_input.txt"), (), "367059", "1952146692");
-    }
+    problem_tests!(Ten, ();
+        example_1[part_1, part_2];
+        part_1 => "367059",
+        part_2 => "1952146692"
+    );
 }
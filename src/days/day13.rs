@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+use clap::Args;
+
+use crate::animation::Recorder;
+use crate::configurable;
+use crate::coordinates::Grid;
+use crate::parse::{self, parse_at, FromLine, ParseError};
+use crate::problem::{self, Answer, Example, Laps, Problem, ProblemState, RunOptions};
+
+pub(crate) struct Thirteen {}
+
+#[derive(FromLine)]
+pub enum Fold {
+    #[pattern("fold along x={0}")]
+    X(isize),
+    #[pattern("fold along y={0}")]
+    Y(isize),
+}
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub(crate) struct Folding {
+    /// How many folds to make before counting the dots in part 1
+    #[arg(long, default_value_t = 1)]
+    folds: usize,
+}
+
+configurable!(Folding);
+
+impl Problem for Thirteen {
+    type Input = (Grid<bool>, Vec<Fold>);
+    type Extra = Folding;
+
+    const DAY: usize = 13;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        let mut blocks = parse::blocks(s);
+
+        let mut paper = Grid::new_from_range(0..10, 0..10);
+        for line in parse::lines(blocks.next().unwrap_or_default()) {
+            paper.set_point(parse::point(s, line)?, true);
+        }
+
+        let folds = parse::each_line(blocks.next().unwrap_or_default(), |line| parse_at(s, line))?;
+        if let Some(extra) = blocks.next() {
+            return Err(ParseError::at(s, extra, "expected the end of the input"));
+        }
+
+        Ok((paper, folds))
+    }
+
+    fn part_1((paper, folds): &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        let folded = fold_all(
+            paper,
+            &folds[..state.extra.folds.min(folds.len())],
+            &state.frames,
+        );
+
+        Answer::from(count_dots(&folded))
+    }
+
+    fn part_2((paper, folds): &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        let folded = fold_all(paper, folds, &state.frames);
+
+        Answer::grid(render(&folded))
+    }
+
+    fn solve((paper, folds): &Self::Input, state: &ProblemState<Self::Extra>, laps: &mut Laps) {
+        // part 2 carries on folding from where part 1 stopped
+        let (first, rest) = folds.split_at(state.extra.folds.min(folds.len()));
+        let folded = fold_all(paper, first, &state.frames);
+        laps.part_1(count_dots(&folded));
+
+        let folded = fold_all(&folded, rest, &state.frames);
+        laps.part_2(Answer::grid(render(&folded)));
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"#,
+            "17",
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n",
+        )]
+    }
+}
+
+fn count_dots(paper: &Grid<bool>) -> usize {
+    paper.indices().filter(|&p| *paper.get_point(p)).count()
+}
+
+fn render(paper: &Grid<bool>) -> String {
+    let mut result = String::new();
+    for y in paper.y_min()..paper.y_max() {
+        for x in paper.x_min()..paper.x_max() {
+            result.push(if *paper.get(x, y) { '#' } else { '.' });
+        }
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Make each fold in turn, recording the paper after every one. The paper is only copied by
+/// folding it, so it's left as it is if there aren't any folds.
+fn fold_all<'a>(paper: &'a Grid<bool>, folds: &[Fold], frames: &Recorder) -> Cow<'a, Grid<bool>> {
+    let mut folded = Cow::Borrowed(paper);
+    for fold in folds {
+        folded = Cow::Owned(fold_paper(&folded, fold));
+        frames.grid(&folded, |&dot| {
+            if dot {
+                [255, 255, 255, 255]
+            } else {
+                [15, 15, 35, 255]
+            }
+        });
+    }
+
+    folded
+}
+
+fn fold_paper(paper: &Grid<bool>, fold: &Fold) -> Grid<bool> {
+    match fold {
+        Fold::X(index) => {
+            let mut result = Grid::new_from_range(paper.x_min()..*index, paper.y_range());
+            for y in paper.y_range() {
+                for x in paper.x_min()..*index {
+                    let other_x = paper.x_max() - x - 1;
+                    result.set(x, y, *paper.get(x, y) || *paper.get(other_x, y));
+                }
+            }
+            result
+        }
+        Fold::Y(index) => {
+            let mut result = Grid::new_from_range(paper.x_range(), paper.y_min()..*index);
+            for y in paper.y_min()..*index {
+                let other_y = paper.y_max() - y - 1;
+                for x in paper.x_range() {
+                    result.set(x, y, *paper.get(x, y) || *paper.get(x, other_y));
+                }
+            }
+            result
+        }
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Thirteen>(options, Folding::default());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::problem_tests;
+
+    use super::*;
+
+    problem_tests!(Thirteen, Folding::default();
+        example_1[part_1, part_2];
+        part_1 => "724",
+        part_2 => r#".##..###....##.###..####.###..#..#.#....
+#..#.#..#....#.#..#.#....#..#.#..#.#....
+#....#..#....#.###..###..#..#.#..#.#....
+#....###.....#.#..#.#....###..#..#.#....
+#..#.#....#..#.#..#.#....#.#..#..#.#....
+.##..#.....##..###..####.#..#..##..####.
+"#
+    );
+}
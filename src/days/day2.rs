@@ -0,0 +1,85 @@
+use crate::parse::{self, parse_at, FromLine, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+pub(crate) struct Two {}
+
+#[derive(FromLine)]
+pub(crate) enum Direction {
+    #[pattern("forward {0}")]
+    Forward(usize),
+    #[pattern("down {0}")]
+    Down(usize),
+    #[pattern("up {0}")]
+    Up(usize),
+}
+
+impl Problem for Two {
+    type Input = Vec<Direction>;
+    type Extra = ();
+
+    const DAY: usize = 2;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |line| parse_at(s, line))
+    }
+
+    fn part_1(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        let mut x = 0;
+        let mut depth = 0;
+        for step in input.iter() {
+            match step {
+                Direction::Forward(distance) => x += *distance,
+                Direction::Down(distance) => depth += *distance,
+                Direction::Up(distance) => depth -= *distance,
+            }
+        }
+
+        Answer::from(x * depth)
+    }
+
+    fn part_2(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        let mut aim = 0;
+        let mut x = 0;
+        let mut depth = 0;
+        for step in input.iter() {
+            match step {
+                Direction::Forward(distance) => {
+                    x += distance;
+                    depth += aim * distance;
+                }
+                Direction::Down(distance) => aim += *distance,
+                Direction::Up(distance) => aim -= *distance,
+            }
+        }
+
+        Answer::from(x * depth)
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"#,
+            "150",
+            "900",
+        )]
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Two>(options, ());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_tests;
+
+    problem_tests!(Two, ();
+        example_1[part_1, part_2];
+        part_1 => "1660158",
+        part_2 => "1604592846"
+    );
+}
@@ -1,9 +1,6 @@
-use advent_of_code_2021::example;
-use advent_of_code_2021::problem::RunFor;
-use advent_of_code_2021::problem::{run, Problem, ProblemState};
-use env_logger::Env;
-
-struct Three {}
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+pub(crate) struct Three {}
 
 type Diagnostic = u16;
 
@@ -26,16 +23,20 @@ impl Problem for Three {
     type Input = (Vec<Diagnostic>, usize);
     type Extra = ();
 
-    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Self::Input {
-        let diagnostics = s
-            .split('\n')
-            .map(|line| Diagnostic::from_str_radix(line, 2).unwrap())
-            .collect();
+    const DAY: usize = 3;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        let diagnostics = parse::each_line(s, |line| {
+            Diagnostic::from_str_radix(line, 2).map_err(|e| ParseError::at(s, line, e))
+        })?;
 
-        (diagnostics, s.split('\n').next().unwrap().len())
+        Ok((
+            diagnostics,
+            parse::lines(s).next().unwrap_or_default().len(),
+        ))
     }
 
-    fn part_1((input, width): &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_1((input, width): &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let mut counts = [0; 16];
         for diagnostic in input {
             for (i, count) in counts.iter_mut().enumerate().take(*width) {
@@ -53,10 +54,10 @@ impl Problem for Three {
         }
         let epsilon = !gamma & ((1 << width) - 1);
 
-        Some(format!("{}", epsilon as u32 * gamma as u32))
+        Answer::from(epsilon as u32 * gamma as u32)
     }
 
-    fn part_2((input, width): &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_2((input, width): &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let mut oxygen = input.clone();
         let mut co2 = input.clone();
         for i in (0..*width).rev() {
@@ -68,11 +69,26 @@ impl Problem for Three {
         let oxygen = oxygen[0];
         let co2 = co2[0];
 
-        Some(format!("{}", oxygen as usize * co2 as usize))
+        Answer::from(oxygen as usize * co2 as usize)
     }
 
-    fn problem_number() -> usize {
-        3
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010"#,
+            "198",
+            "230",
+        )]
     }
 }
 
@@ -95,31 +111,18 @@ where
     }
 }
 
-fn main() {
-    env_logger::init_from_env(Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "warn"));
-
-    example!(Three; RunFor::Both, (), r#"00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010"#);
-    run::<Three>((), include_str!("3_input.txt"));
+pub fn run(options: &RunOptions) {
+    problem::run::<Three>(options, ());
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use advent_of_code_2021::problem::assert_solution;
+    use crate::problem_tests;
 
-    #[test]
-    fn test() {
-        assert_solution::<Three>(include_str!("3_input.txt"), (), "1025636", "793873");
-    }
+    problem_tests!(Three, ();
+        example_1[part_1, part_2];
+        part_1 => "1025636",
+        part_2 => "793873"
+    );
 }
@@ -0,0 +1,167 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Laps, Problem, ProblemState, RunOptions};
+
+pub(crate) struct Four {}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Board {
+    boxes: [[Box; 5]; 5],
+}
+
+#[derive(Debug, Clone, Default)]
+struct Box {
+    number: usize,
+    marked: bool,
+}
+
+impl Board {
+    fn mark_drawn_number(&mut self, number: usize) -> bool {
+        let mut found = vec![];
+        for (y, row) in self.boxes.iter_mut().enumerate() {
+            for (x, current_box) in row.iter_mut().enumerate() {
+                if current_box.number == number {
+                    current_box.marked = true;
+                    found.push((x, y));
+                }
+            }
+        }
+
+        if !found.is_empty() {
+            for (x, y) in found {
+                let mut x_marked = true;
+                let mut y_marked = true;
+                for i in 0..5 {
+                    y_marked = y_marked && self.boxes[y][i].marked;
+                    x_marked = x_marked && self.boxes[i][x].marked;
+                }
+                if x_marked || y_marked {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn unmarked_sum(&self) -> usize {
+        self.boxes
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|b| if !b.marked { b.number } else { 0 })
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+impl Problem for Four {
+    type Input = (Vec<usize>, Vec<Board>);
+    type Extra = ();
+
+    const DAY: usize = 4;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        let mut blocks = parse::blocks(s);
+        let drawn_numbers = parse::comma_separated(s, blocks.next().unwrap_or_default())?;
+
+        let mut boards = vec![];
+        for block in blocks {
+            let mut boxes: [[Box; 5]; 5] = Default::default();
+            let mut rows = parse::lines(block);
+            for board_box in &mut boxes {
+                let row = rows
+                    .next()
+                    .ok_or_else(|| ParseError::at(s, block, "expected 5 board rows"))?;
+                *board_box = parse::whitespace_separated(s, row)?
+                    .into_iter()
+                    .map(|number| Box {
+                        number,
+                        marked: false,
+                    })
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .map_err(|_| ParseError::at(s, row, "expected 5 numbers in a board row"))?
+            }
+            if let Some(row) = rows.next() {
+                return Err(ParseError::at(s, row, "expected 5 board rows"));
+            }
+            boards.push(Board { boxes })
+        }
+
+        Ok((drawn_numbers, boards))
+    }
+
+    fn part_1((draws, boards): &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        winning_scores(draws, boards).next().into()
+    }
+
+    fn part_2((draws, boards): &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        winning_scores(draws, boards).last().into()
+    }
+
+    fn solve((draws, boards): &Self::Input, _state: &ProblemState<Self::Extra>, laps: &mut Laps) {
+        // keep playing after the first winner to find the last
+        let mut scores = winning_scores(draws, boards);
+        let first = scores.next();
+        laps.part_1(first);
+        laps.part_2(scores.last().or(first));
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7"#,
+            "4512",
+            "1924",
+        )]
+    }
+}
+
+/// The score of every board in the order they win, a board stops playing once it's won
+fn winning_scores<'a>(draws: &'a [usize], boards: &[Board]) -> impl Iterator<Item = usize> + 'a {
+    let mut boards = boards.to_vec();
+    draws.iter().flat_map(move |&draw| {
+        let mut scores = vec![];
+        boards.retain_mut(|board| {
+            let won = board.mark_drawn_number(draw);
+            if won {
+                scores.push(board.unmarked_sum() * draw);
+            }
+            !won
+        });
+        scores
+    })
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Four>(options, ());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_tests;
+
+    problem_tests!(Four, ();
+        example_1[part_1, part_2];
+        part_1 => "2496",
+        part_2 => "25925"
+    );
+}
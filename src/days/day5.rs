@@ -0,0 +1,117 @@
+use crate::animation::Recorder;
+use crate::coordinates::two_d::{Point, PointLike};
+use crate::coordinates::Grid;
+use crate::parse::{self, parse_at, FromLine, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+
+pub(crate) struct Five {}
+
+#[derive(FromLine)]
+#[pattern("{x1},{y1} -> {x2},{y2}")]
+struct Vent {
+    x1: isize,
+    y1: isize,
+    x2: isize,
+    y2: isize,
+}
+
+impl Problem for Five {
+    type Input = Vec<(Point, Point)>;
+    type Extra = ();
+
+    const DAY: usize = 5;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |line| {
+            let vent: Vent = parse_at(s, line)?;
+            Ok((Point::new(vent.x1, vent.y1), Point::new(vent.x2, vent.y2)))
+        })
+    }
+
+    fn part_1(vents: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::from(calculate_vent_danger(vents, false, &state.frames))
+    }
+
+    fn part_2(vents: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::from(calculate_vent_danger(vents, true, &state.frames))
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#,
+            "5",
+            "12",
+        )]
+    }
+}
+
+/// Draw the vents on the ocean floor, recording it after each one, and count the points where
+/// they overlap
+fn calculate_vent_danger(
+    vents: &[(Point, Point)],
+    handle_diagonals: bool,
+    frames: &Recorder,
+) -> usize {
+    let mut ocean_floor: Grid<usize> = Grid::new_from_range(0..10, 0..10);
+    for (start, end) in vents {
+        if !handle_diagonals && start.x != end.x && start.y != end.y {
+            continue;
+        }
+
+        let mut inc = Point::new(end.x - start.x, end.y - start.y);
+        if inc.x != 0 {
+            inc.x /= inc.x.abs();
+        }
+        if inc.y != 0 {
+            inc.y /= inc.y.abs();
+        }
+        let mut point = *start;
+        while &point != end {
+            ocean_floor.set_point(point, ocean_floor.get_point(point) + 1);
+            point.inc(&inc);
+        }
+        ocean_floor.set_point(point, ocean_floor.get_point(point) + 1);
+        frames.grid(&ocean_floor, |&vents| match vents {
+            0 => [0, 20, 50, 255],
+            1 => [40, 140, 220, 255],
+            _ => [255, 60, 20, 255],
+        });
+    }
+
+    ocean_floor.enumerate().fold(
+        0,
+        |result, (_, &danger_level)| {
+            if danger_level > 1 {
+                result + 1
+            } else {
+                result
+            }
+        },
+    )
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Five>(options, ());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::problem_tests;
+
+    use super::*;
+
+    problem_tests!(Five, ();
+        example_1[part_1, part_2];
+        part_1 => "8350",
+        part_2 => "19374"
+    );
+}
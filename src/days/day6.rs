@@ -0,0 +1,89 @@
+use std::mem::swap;
+
+use clap::Args;
+
+use crate::configurable;
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+
+pub(crate) struct Six {}
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub(crate) struct Breeding {
+    /// Breed the fish for this many days in both parts, instead of 80 and 256
+    #[arg(long)]
+    days: Option<usize>,
+}
+
+configurable!(Breeding);
+
+impl Problem for Six {
+    type Input = Vec<usize>;
+    type Extra = Breeding;
+
+    const DAY: usize = 6;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::comma_separated(s, s)
+    }
+
+    fn part_1(fishes: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::from(breed_fish(fishes, state.extra.days.unwrap_or(80)))
+    }
+
+    fn part_2(fishes: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::from(breed_fish(fishes, state.extra.days.unwrap_or(256)))
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![
+            Example::new("3,4,3,1,2", "5934", "26984457539"),
+            Example::part_1("3,4,3,1,2", "26").with_extra(Breeding { days: Some(18) }),
+        ]
+    }
+}
+
+/// How many fish there are after `days`, or `None` once there are too many to count
+fn breed_fish(fish_days: &[usize], days: usize) -> Option<usize> {
+    let mut fish_counts = [0usize; 9];
+    for &fish in fish_days {
+        fish_counts[fish] += 1;
+    }
+
+    let mut previous = 0;
+    for _ in 0..days {
+        for fish_count in fish_counts.iter_mut().rev() {
+            swap(fish_count, &mut previous)
+        }
+        fish_counts[6] = fish_counts[6].checked_add(previous)?;
+        fish_counts[8] += previous;
+        previous = 0;
+    }
+
+    fish_counts
+        .iter()
+        .try_fold(0usize, |total, &count| total.checked_add(count))
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Six>(options, Breeding::default());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::problem_tests;
+
+    use super::*;
+
+    problem_tests!(Six, Breeding::default();
+        example_1[part_1, part_2],
+        example_2[part_1];
+        part_1 => "362666",
+        part_2 => "1640526601595"
+    );
+
+    #[test]
+    fn too_many_fish() {
+        assert_eq!(breed_fish(&[3, 4, 3, 1, 2], 1000), None);
+    }
+}
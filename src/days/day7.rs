@@ -0,0 +1,86 @@
+use crate::parse::{self, ParseError};
+use crate::problem::timeout::CancelToken;
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
+
+pub(crate) struct Seven {}
+
+impl Problem for Seven {
+    type Input = Vec<usize>;
+    type Extra = ();
+
+    const DAY: usize = 7;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::comma_separated(s, s)
+    }
+
+    fn part_1(positions: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        find_cheapest_position(positions, linear_fuel_usage, &state.cancel).into()
+    }
+
+    fn part_2(positions: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+        find_cheapest_position(positions, exponential_fuel_usage, &state.cancel).into()
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new("16,1,2,0,4,2,7,1,2,14", "37", "168")]
+    }
+}
+
+/// Try every position between the crabs, giving up with `None` if we're cancelled
+fn find_cheapest_position<F>(positions: &[usize], cost_fn: F, cancel: &CancelToken) -> Option<usize>
+where
+    F: Fn(&[usize], usize) -> usize,
+{
+    let &min = positions.iter().min().unwrap();
+    let &max = positions.iter().max().unwrap();
+
+    let mut least_expensive = usize::MAX;
+    for position in min..=max {
+        if cancel.is_cancelled() {
+            return None;
+        }
+
+        let cost = cost_fn(positions, position);
+        if cost < least_expensive {
+            least_expensive = cost;
+        }
+    }
+
+    Some(least_expensive)
+}
+
+fn distances(positions: &[usize], position: usize) -> Vec<usize> {
+    positions.iter().map(|&p| p.abs_diff(position)).collect()
+}
+
+fn linear_fuel_usage(positions: &[usize], position: usize) -> usize {
+    distances(positions, position).iter().sum()
+}
+
+fn exponential_fuel_usage(positions: &[usize], position: usize) -> usize {
+    distances(positions, position)
+        .iter()
+        .map(|&distance| {
+            // https://en.wikipedia.org/wiki/Triangular_number
+            (distance.pow(2) + distance) / 2
+        })
+        .sum()
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Seven>(options, ());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::problem_tests;
+
+    use super::*;
+
+    problem_tests!(Seven, ();
+        example_1[part_1, part_2];
+        part_1 => "344535",
+        part_2 => "95581659"
+    );
+}
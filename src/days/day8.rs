@@ -1,15 +1,11 @@
-use env_logger::Env;
 use std::collections::HashSet;
 
-use advent_of_code_2021::example;
-use advent_of_code_2021::problem::RunFor;
-use advent_of_code_2021::problem::{run, Problem, ProblemState};
-use lazy_static::lazy_static;
-use regex::Regex;
+use crate::parse::{self, parse_at, FromLine, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
 use std::default::Default;
 use std::fmt::{Debug, Formatter};
 use std::ops::{Index, IndexMut};
-use std::str::from_utf8;
+use std::str::{from_utf8, FromStr};
 
 const A: usize = 0;
 const B: usize = 1;
@@ -19,9 +15,11 @@ const E: usize = 4;
 const F: usize = 5;
 const G: usize = 6;
 
-struct Eight {}
+pub(crate) struct Eight {}
 
-struct Display {
+#[derive(FromLine)]
+#[pattern("{signals*} | {digits*}")]
+pub(crate) struct Display {
     signals: [DisplayDigit; 10],
     digits: [DisplayDigit; 4],
 }
@@ -174,6 +172,26 @@ impl DisplayDigit {
     }
 }
 
+impl FromStr for DisplayDigit {
+    type Err = ParseError;
+
+    fn from_str(segments: &str) -> Result<Self, Self::Err> {
+        let mut digit = DisplayDigit::default();
+        for (i, segment) in segments.char_indices() {
+            if !('a'..='g').contains(&segment) {
+                return Err(ParseError::at(
+                    segments,
+                    &segments[i..i + segment.len_utf8()],
+                    "expected a segment from a to g",
+                ));
+            }
+            digit[(segment as u8 - b'a') as usize] = true;
+        }
+
+        Ok(digit)
+    }
+}
+
 impl Index<usize> for DisplayDigit {
     type Output = bool;
 
@@ -192,34 +210,13 @@ impl Problem for Eight {
     type Input = Vec<Display>;
     type Extra = ();
 
-    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Self::Input {
-        lazy_static! {
-            static ref DISPLAY_RE: Regex = Regex::new(r"(\w+) (\w+) (\w+) (\w+) (\w+) (\w+) (\w+) (\w+) (\w+) (\w+) \| (\w+) (\w+) (\w+) (\w+)").unwrap();
-        }
-
-        s.split('\n')
-            .map(|line| {
-                let parsed_row = DISPLAY_RE.captures(line).unwrap();
-                let mut signals: [DisplayDigit; 10] = Default::default();
-                for i in 0..10 {
-                    for &signal in parsed_row[i + 1].as_bytes() {
-                        signals[i][(signal - b'a') as usize] = true;
-                    }
-                }
-
-                let mut digits: [DisplayDigit; 4] = Default::default();
-                for i in 0..4 {
-                    for &signal in parsed_row[i + 11].as_bytes() {
-                        digits[i][(signal - b'a') as usize] = true;
-                    }
-                }
+    const DAY: usize = 8;
 
-                Display { signals, digits }
-            })
-            .collect()
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |line| parse_at(s, line))
     }
 
-    fn part_1(displays: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_1(displays: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let simple_count = displays
             .iter()
             .map(|display| {
@@ -240,10 +237,10 @@ impl Problem for Eight {
             })
             .sum::<usize>();
 
-        Some(format!("{}", simple_count))
+        Answer::from(simple_count)
     }
 
-    fn part_2(displays: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_2(displays: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let result = displays
             .iter()
             .map(|display| {
@@ -256,19 +253,17 @@ impl Problem for Eight {
             })
             .sum::<usize>();
 
-        Some(format!("{}", result))
+        Answer::from(result)
     }
 
-    fn problem_number() -> usize {
-        8
-    }
-}
-
-fn main() {
-    env_logger::init_from_env(Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "warn"));
-
-    example!(Eight; RunFor::Part2, (), r#"acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf"#);
-    example!(Eight; RunFor::Both, (), r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![
+            Example::part_2(
+                r#"acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf"#,
+                "5353",
+            ),
+            Example::new(
+                r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
     edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
     fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
     fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
@@ -277,18 +272,27 @@ fn main() {
     dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
     bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
     egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-    gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#);
-    run::<Eight>((), include_str!("8_input.txt"));
+    gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#,
+                "26",
+                "61229",
+            ),
+        ]
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Eight>(options, ());
 }
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_2021::problem::assert_solution;
+    use crate::problem_tests;
 
     use super::*;
 
-    #[test]
-    fn test() {
-        assert_solution::<Eight>(include_str!("8_input.txt"), (), "365", "975706");
-    }
+    problem_tests!(Eight, ();
+        example_1[part_2], example_2[part_1, part_2];
+        part_1 => "365",
+        part_2 => "975706"
+    );
 }
@@ -1,43 +1,35 @@
-use env_logger::Env;
 use std::collections::HashSet;
 
-use advent_of_code_2021::coordinates::two_d::{Point, PointLike};
-use advent_of_code_2021::coordinates::Grid;
-use advent_of_code_2021::example;
-use advent_of_code_2021::problem::RunFor;
-use advent_of_code_2021::problem::{run, Problem, ProblemState};
+use crate::coordinates::two_d::{Point, PointLike};
+use crate::coordinates::Grid;
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunOptions};
 
-use std::default::Default;
-
-struct Nine {}
+pub(crate) struct Nine {}
 
 impl Problem for Nine {
     type Input = Grid<usize>;
     type Extra = ();
 
-    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Self::Input {
-        let mut height_map = Grid::new_from_range(0..5, 0..5);
-        height_map.default = usize::MAX;
+    const DAY: usize = 9;
 
-        s.split('\n').enumerate().for_each(|(y, line)| {
-            for (x, c) in line.chars().enumerate() {
-                height_map.set(x as isize, y as isize, c.to_digit(10).unwrap() as usize);
-            }
-        });
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        let mut height_map: Grid<usize> = parse::digit_grid(s)?;
+        height_map.default = usize::MAX;
 
-        height_map
+        Ok(height_map)
     }
 
-    fn part_1(height_map: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_1(height_map: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let low_sum = low_points(height_map)
             .iter()
             .map(|&low_point| height_map.get_point(low_point) + 1)
             .sum::<usize>();
 
-        Some(format!("{}", low_sum))
+        Answer::from(low_sum)
     }
 
-    fn part_2(height_map: &Self::Input, _state: &ProblemState<Self::Extra>) -> Option<String> {
+    fn part_2(height_map: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
         let mut basin_sizes = low_points(height_map)
             .iter()
             .map(|&low_point| basin_size(HashSet::from([low_point]), HashSet::new(), height_map))
@@ -46,11 +38,19 @@ impl Problem for Nine {
 
         let basin_sum = basin_sizes.into_iter().rev().take(3).product::<usize>();
 
-        Some(format!("{}", basin_sum))
+        Answer::from(basin_sum)
     }
 
-    fn problem_number() -> usize {
-        9
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(
+            r#"2199943210
+3987894921
+9856789892
+8767896789
+9899965678"#,
+            "15",
+            "1134",
+        )]
     }
 }
 
@@ -94,25 +94,19 @@ fn basin_size(
     }
 }
 
-fn main() {
-    env_logger::init_from_env(Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "warn"));
-
-    example!(Nine; RunFor::Both, (), r#"2199943210
-3987894921
-9856789892
-8767896789
-9899965678"#);
-    run::<Nine>((), include_str!("9_input.txt"));
+pub fn run(options: &RunOptions) {
+    problem::run::<Nine>(options, ());
 }
 
 #[cfg(test)]
 mod test {
-    use advent_of_code_2021::problem::assert_solution;
+    use crate::problem_tests;
 
     use super::*;
 
-    #[test]
-    fn test() {
-        assert_solution::<Nine>(include_str!("9_input.txt"), (), "548", "786048");
-    }
+    problem_tests!(Nine, ();
+        example_1[part_1, part_2];
+        part_1 => "548",
+        part_2 => "786048"
+    );
 }
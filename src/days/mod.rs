@@ -0,0 +1,194 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use crate::problem::{Problem, RunFor, RunOptions};
+use crate::report::buffer::Buffer;
+
+pub mod day1;
+pub mod day10;
+pub mod day13;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// A day that can be run by number from the `aoc` binary
+pub struct Day {
+    pub number: usize,
+    /// The parts the day has solutions for
+    pub parts: RunFor,
+    pub run: fn(&RunOptions),
+}
+
+/// Builds `DAYS` from each day's module and problem, checking their numbers while compiling
+macro_rules! register_days {
+    ( $( $module:ident :: $problem:ident ),* $(,)? ) => {
+        pub static DAYS: &[Day] = &[
+            $(
+                Day {
+                    number: <$module::$problem as Problem>::DAY,
+                    parts: <$module::$problem as Problem>::PARTS,
+                    run: $module::run,
+                },
+            )*
+        ];
+
+        const _: () = check_days(&[ $( <$module::$problem as Problem>::DAY ),* ]);
+    };
+}
+
+register_days!(
+    day1::One,
+    day2::Two,
+    day3::Three,
+    day4::Four,
+    day5::Five,
+    day6::Six,
+    day7::Seven,
+    day8::Eight,
+    day9::Nine,
+    day10::Ten,
+    day13::Thirteen,
+);
+
+/// Days have to be in order, without any duplicates, and within the 25 days of the event
+const fn check_days(days: &[usize]) {
+    let mut i = 0;
+    while i < days.len() {
+        if days[i] < 1 || days[i] > 25 {
+            panic!("Day numbers must be from 1 to 25");
+        }
+        if i > 0 && days[i] == days[i - 1] {
+            panic!("The same day number was registered twice");
+        }
+        if i > 0 && days[i] < days[i - 1] {
+            panic!("Days must be registered in order");
+        }
+        i += 1;
+    }
+}
+
+/// Run each day, up to `jobs` at a time. Everything is still reported in the order of `days`.
+/// Benchmarks and submissions always run one day at a time.
+pub fn run_all(days: &[&Day], options: &RunOptions, jobs: usize) {
+    if jobs <= 1 || options.bench.is_some() || options.submitter.is_some() {
+        for day in days {
+            (day.run)(options);
+        }
+        return;
+    }
+
+    let buffers = days
+        .iter()
+        .map(|_| Arc::new(Buffer::default()))
+        .collect::<Vec<_>>();
+    let next_day = AtomicUsize::new(0);
+    let (finished, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let finished = finished.clone();
+            let (buffers, next_day) = (&buffers, &next_day);
+            scope.spawn(move || loop {
+                let i = next_day.fetch_add(1, Ordering::SeqCst);
+                if i >= days.len() {
+                    break;
+                }
+
+                let mut day_options = options.clone();
+                day_options.reporter = buffers[i].clone();
+                (days[i].run)(&day_options);
+                let _ = finished.send(i);
+            });
+        }
+        drop(finished);
+
+        // report each day once it and every day before it are done
+        let mut done = vec![false; days.len()];
+        let mut next_report = 0;
+        for i in receiver {
+            done[i] = true;
+            while next_report < days.len() && done[next_report] {
+                buffers[next_report].replay(&*options.reporter);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use crate::report::{PartReport, Reporter};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Messages(Mutex<Vec<String>>);
+
+    impl Reporter for Messages {
+        fn part(&self, _report: &PartReport) {}
+
+        fn message(&self, message: &str) {
+            self.0.lock().unwrap().push(message.to_string());
+        }
+    }
+
+    fn slow(options: &RunOptions) {
+        thread::sleep(Duration::from_millis(50));
+        options.reporter.message("slow");
+    }
+
+    fn fast(options: &RunOptions) {
+        options.reporter.message("fast");
+    }
+
+    #[test]
+    fn reports_in_order() {
+        let reporter = Arc::new(Messages::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            ..Default::default()
+        };
+        let days = [
+            Day {
+                number: 1,
+                parts: RunFor::Both,
+                run: slow,
+            },
+            Day {
+                number: 2,
+                parts: RunFor::Both,
+                run: fast,
+            },
+            Day {
+                number: 3,
+                parts: RunFor::Both,
+                run: slow,
+            },
+        ];
+
+        run_all(&days.iter().collect::<Vec<_>>(), &options, 3);
+
+        assert_eq!(*reporter.0.lock().unwrap(), ["slow", "fast", "slow"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "twice")]
+    fn duplicate_day() {
+        check_days(&[5, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "from 1 to 25")]
+    fn out_of_range_day() {
+        check_days(&[26]);
+    }
+}
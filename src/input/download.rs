@@ -0,0 +1,130 @@
+use std::fmt::{self, Debug, Formatter};
+use std::fs;
+use std::path::Path;
+
+use log::info;
+
+use crate::input::InputError;
+
+pub static DEFAULT_BASE_URL: &str = "https://adventofcode.com/2021";
+pub static SESSION_ENV: &str = "AOC_SESSION";
+
+/// Sent with every request, the site asks automated tools to say who they are
+pub(crate) static USER_AGENT: &str = "github.com/dylanowen/advent-of-code-2021";
+
+/// Fetches inputs from the Advent of Code site, or anything that serves `<base_url>/day/<day>/input`
+#[derive(Clone, PartialEq)]
+pub struct Downloader {
+    base_url: String,
+    session: String,
+}
+
+impl Downloader {
+    pub fn new<S: Into<String>>(session: S) -> Downloader {
+        Downloader {
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+        }
+    }
+
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> Downloader {
+        self.base_url = base_url.into().trim_end_matches('/').into();
+        self
+    }
+
+    pub fn fetch(&self, day: usize) -> Result<String, InputError> {
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        info!("Downloading {}", url);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| InputError::Download(e.to_string()))?;
+
+        response
+            .into_string()
+            .map_err(|e| InputError::Download(format!("{}: {}", url, e)))
+    }
+
+    /// Read the input from `path` if we've already downloaded it, otherwise fetch and save it there
+    pub fn fetch_cached(&self, day: usize, path: &Path) -> Result<String, InputError> {
+        if path.exists() {
+            let input = super::read_file(path.to_path_buf())?;
+            // an empty input is a placeholder from `aoc new`, fetch the real one over it
+            if !input.trim().is_empty() {
+                return Ok(input);
+            }
+        }
+
+        let input = self.fetch(day)?;
+        if input.trim().is_empty() {
+            // don't cache an empty input, it would stop us from ever fetching the real one
+            return Err(InputError::Empty(format!(
+                "{}/day/{}/input",
+                self.base_url, day
+            )));
+        }
+
+        let io_error = |error| InputError::Io {
+            source: path.display().to_string(),
+            error,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(path, &input).map_err(io_error)?;
+
+        Ok(input)
+    }
+}
+
+/// Leaves out the session, it's as good as a password
+impl Debug for Downloader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Downloader")
+            .field("base_url", &self.base_url)
+            .field("session", &"<redacted>")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::input::InputSource;
+    use crate::temp_dir::TempDir;
+    use crate::test_server::TestServer;
+
+    use super::*;
+
+    #[test]
+    fn downloads_once() {
+        let server = TestServer::start(|request| {
+            if request.method == "GET"
+                && request.path == "/day/3/input"
+                && request.header("Cookie") == Some("session=secret")
+            {
+                (200, "1\n2\n3\n".to_string())
+            } else {
+                (400, String::new())
+            }
+        });
+
+        let dir = TempDir::new("download");
+        let source = InputSource::Download {
+            dir: dir.path().to_path_buf(),
+            downloader: Downloader::new("secret").with_base_url(server.base_url()),
+        };
+        assert!(!format!("{:?}", source).contains("secret"));
+
+        // an empty placeholder is downloaded over
+        fs::write(dir.join("3.txt"), "").unwrap();
+        assert_eq!(source.load(3).unwrap(), "1\n2\n3\n");
+        assert_eq!(source.load(3).unwrap(), "1\n2\n3\n");
+        assert_eq!(fs::read_to_string(dir.join("3.txt")).unwrap(), "1\n2\n3\n");
+        assert_eq!(server.requests().len(), 1);
+
+        assert!(matches!(source.load(4), Err(InputError::Download(_))));
+        assert!(!dir.join("4.txt").exists());
+    }
+}
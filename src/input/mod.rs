@@ -0,0 +1,139 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::PathBuf;
+use std::{fmt, fs, io};
+
+use crate::input::download::Downloader;
+
+pub mod download;
+pub mod normalise;
+
+pub static DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// Where to read a problem's input from at runtime
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// A directory holding one `<day>.txt` file per day
+    Directory(PathBuf),
+    /// Like `Directory`, but downloads and saves any input that isn't there yet
+    Download {
+        dir: PathBuf,
+        downloader: Downloader,
+    },
+    /// A single input file, used regardless of the day
+    File(PathBuf),
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(PathBuf::from(DEFAULT_INPUTS_DIR))
+    }
+}
+
+impl InputSource {
+    pub fn load(&self, day: usize) -> Result<String, InputError> {
+        let input = match self {
+            InputSource::Directory(dir) => read_file(dir.join(format!("{}.txt", day)))?,
+            InputSource::Download { dir, downloader } => {
+                downloader.fetch_cached(day, &dir.join(format!("{}.txt", day)))?
+            }
+            InputSource::File(path) => read_file(path.clone())?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| InputError::Io {
+                        source: self.to_string(),
+                        error,
+                    })?;
+                input
+            }
+        };
+
+        if input.trim().is_empty() {
+            Err(InputError::Empty(self.describe(day)))
+        } else {
+            Ok(input)
+        }
+    }
+
+    fn describe(&self, day: usize) -> String {
+        match self {
+            InputSource::Directory(dir) | InputSource::Download { dir, .. } => {
+                dir.join(format!("{}.txt", day)).display().to_string()
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Directory(dir) | InputSource::Download { dir, .. } => {
+                write!(f, "{}", dir.display())
+            }
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|error| {
+        if error.kind() == io::ErrorKind::NotFound {
+            InputError::Missing(path.display().to_string())
+        } else {
+            InputError::Io {
+                source: path.display().to_string(),
+                error,
+            }
+        }
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(String),
+    Empty(String),
+    Download(String),
+    Io { source: String, error: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(source) => write!(f, "input not found: {}", source),
+            InputError::Empty(source) => write!(f, "input is empty: {}", source),
+            InputError::Download(message) => write!(f, "couldn't download input: {}", message),
+            InputError::Io { source, error } => {
+                write!(f, "couldn't read input {}: {}", source, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn missing_and_empty() {
+        let dir = TempDir::new("inputs");
+        fs::write(dir.join("2.txt"), "\n\n").unwrap();
+        fs::write(dir.join("3.txt"), "1\n2").unwrap();
+
+        let source = InputSource::Directory(dir.path().to_path_buf());
+        assert!(matches!(source.load(1), Err(InputError::Missing(_))));
+        assert!(matches!(source.load(2), Err(InputError::Empty(_))));
+        assert_eq!(source.load(3).unwrap(), "1\n2");
+        assert_eq!(
+            InputSource::File(dir.join("3.txt")).load(25).unwrap(),
+            "1\n2"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+
+/// Something `normalise` had to change about an input
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    ByteOrderMark,
+    CrLf,
+    /// Blank or whitespace only lines after the last line of the input
+    TrailingBlankLines(usize),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::ByteOrderMark => write!(f, "removed a byte order mark"),
+            Change::CrLf => write!(f, "converted CRLF line endings"),
+            Change::TrailingBlankLines(1) => write!(f, "removed a trailing blank line"),
+            Change::TrailingBlankLines(lines) => {
+                write!(f, "removed {} trailing blank lines", lines)
+            }
+        }
+    }
+}
+
+/// Clean up an input saved by a browser or editor before it's parsed: remove any byte order
+/// mark, convert CRLF line endings to LF and, if `trim_trailing_lines` is set, remove any blank
+/// lines from the end along with the last line's newline. Returns what was changed, a lone final
+/// newline isn't worth mentioning.
+pub fn normalise(input: &str, trim_trailing_lines: bool) -> (Cow<'_, str>, Vec<Change>) {
+    let mut changes = vec![];
+
+    let mut input = Cow::Borrowed(input);
+    if let Some(rest) = input.strip_prefix('\u{feff}') {
+        input = Cow::Owned(rest.to_string());
+        changes.push(Change::ByteOrderMark);
+    }
+    if input.contains("\r\n") {
+        input = Cow::Owned(input.replace("\r\n", "\n"));
+        changes.push(Change::CrLf);
+    }
+
+    if trim_trailing_lines {
+        let content_end = input.trim_end().len();
+        let line_end = input[content_end..]
+            .find('\n')
+            .map_or(input.len(), |i| content_end + i);
+        let trailing = &input[line_end..];
+
+        // the first line is what's left of the last line of the input, and the last is empty if
+        // the blank lines end with a newline
+        let blank_lines = match trailing.split('\n').count() {
+            0 | 1 => 0,
+            count if trailing.ends_with('\n') => count - 2,
+            count => count - 1,
+        };
+        if blank_lines > 0 {
+            changes.push(Change::TrailingBlankLines(blank_lines));
+        }
+
+        input = match input {
+            Cow::Borrowed(input) => Cow::Borrowed(&input[..line_end]),
+            Cow::Owned(mut input) => {
+                input.truncate(line_end);
+                Cow::Owned(input)
+            }
+        };
+    }
+
+    (input, changes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalises() {
+        assert_eq!(normalise("1\n2", true), (Cow::Borrowed("1\n2"), vec![]));
+        assert_eq!(normalise("1\n2\n", true), (Cow::Borrowed("1\n2"), vec![]));
+        assert_eq!(
+            normalise("1\n2\n", false),
+            (Cow::Borrowed("1\n2\n"), vec![])
+        );
+        assert_eq!(
+            normalise("1\n2  \n\n \n", true),
+            (Cow::Borrowed("1\n2  "), vec![Change::TrailingBlankLines(2)])
+        );
+        assert_eq!(
+            normalise("\u{feff}1\r\n2\r\n\r\n", true),
+            (
+                Cow::Owned("1\n2".to_string()),
+                vec![
+                    Change::ByteOrderMark,
+                    Change::CrLf,
+                    Change::TrailingBlankLines(1)
+                ]
+            )
+        );
+        assert_eq!(
+            normalise("1\r\n\r\n", false),
+            (Cow::Owned("1\n\n".to_string()), vec![Change::CrLf])
+        );
+        // blank lines in the middle are left alone
+        assert_eq!(
+            normalise("1\n\n2\n", true),
+            (Cow::Borrowed("1\n\n2"), vec![])
+        );
+    }
+}
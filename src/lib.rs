@@ -1,2 +1,20 @@
+// lets `#[derive(FromLine)]` refer to this crate by name, from inside it too
+extern crate self as advent_of_code_2021;
+
+pub mod animation;
+pub mod answers;
+pub mod bench;
 pub mod coordinates;
+pub mod days;
+pub mod input;
+pub mod parse;
 pub mod problem;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod toml_file;
+
+#[cfg(test)]
+mod temp_dir;
+#[cfg(test)]
+mod test_server;
@@ -0,0 +1,415 @@
+//! Helpers for turning puzzle inputs into something to solve with. Anything that can fail takes
+//! the whole `input` alongside the `text` being parsed so errors can point at where it went wrong.
+//!
+//! Lines with a fixed shape can `#[derive(FromLine)]` instead of splitting them by hand. The
+//! `#[pattern("...")]` on the struct, or on each variant of an enum, is literal text around the
+//! fields to parse:
+//!
+//! - `{name}` (or `{0}` for tuple fields) parses the field with its `FromStr`
+//! - `{name*}` parses a `Vec` or array field from whitespace separated values, and `{name*,}`
+//!   from values separated by `,` (or any other text after the `*`)
+//! - `{{` and `}}` are a literal `{` and `}`
+//!
+//! ```ignore
+//! #[derive(FromLine)]
+//! #[pattern("{x1},{y1} -> {x2},{y2}")]
+//! struct Vent { x1: isize, y1: isize, x2: isize, y2: isize }
+//! ```
+//!
+//! Enums try each variant in order. Parse these with `parse_at` so their errors point into the
+//! whole input rather than just the line.
+
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub use advent_of_code_2021_derive::FromLine;
+
+use crate::coordinates::two_d::{Point, PointLike};
+use crate::coordinates::Grid;
+
+/// An error found while parsing a problem's input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1 based line number of the offending text
+    pub line: usize,
+    /// 1 based column (in chars) of the offending text
+    pub column: usize,
+    pub text: String,
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Build an error for `offending`, which should be a slice of `input` so we can find its
+    /// position. If it isn't we fall back to searching for it, and then to the end of the input.
+    pub fn at<M: ToString>(input: &str, offending: &str, message: M) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offending_start = offending.as_ptr() as usize;
+        let offset = if offending_start >= start && offending_start <= start + input.len() {
+            offending_start - start
+        } else {
+            input.find(offending).unwrap_or(input.len())
+        };
+
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or(input.len());
+
+        ParseError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: offending.to_string(),
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Point an error found while parsing `text` on its own at the same place in `input`, which
+    /// `text` should be a slice of
+    fn within(self, input: &str, text: &str) -> ParseError {
+        let line_start = text
+            .split_inclusive('\n')
+            .take(self.line - 1)
+            .map(str::len)
+            .sum::<usize>();
+        let start = text[line_start..]
+            .char_indices()
+            .nth(self.column - 1)
+            .map_or(text.len(), |(i, _)| line_start + i);
+        let offending = text
+            .get(start..start + self.text.len())
+            .unwrap_or(&text[start..]);
+
+        ParseError::at(input, offending, self.message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "parse error at line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.message, self.text
+        )?;
+        writeln!(f, "    {}", self.line_text)?;
+        write!(
+            f,
+            "    {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text`, which should be a slice of `input`, reporting where it was on failure. Types
+/// that fail with their own `ParseError`, like `FromLine` types, keep their position in `text`.
+pub fn parse_at<T>(input: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display + 'static,
+{
+    text.parse::<T>()
+        .map_err(|e| match (&e as &dyn Any).downcast_ref::<ParseError>() {
+            Some(error) => error.clone().within(input, text),
+            None => ParseError::at(input, text, e),
+        })
+}
+
+/// The lines of `text` without their line endings, which may be `\n` or `\r\n`. Trailing blank
+/// lines are ignored, so inputs may or may not end with a newline.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.trim_end_matches(['\r', '\n']).lines()
+}
+
+/// Parse every line of `input` with `parser`, stopping at the first error
+pub fn each_line<'a, T, F>(input: &'a str, parser: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    lines(input).map(parser).collect()
+}
+
+/// Paragraphs of `text` separated by one or more blank lines, each without its surrounding blank
+/// lines
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let mut lines = lines(text).peekable();
+    std::iter::from_fn(move || {
+        let first = lines.find(|line| !line.trim().is_empty())?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
+        }
+
+        Some(&text[offset(text, first)..offset(text, last) + last.len()])
+    })
+}
+
+/// Parse the comma separated values in `text`, a slice of `input`, like `3,4,3,1,2`
+pub fn comma_separated<T>(input: &str, text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display + 'static,
+{
+    text.trim_end_matches(['\r', '\n'])
+        .split(',')
+        .map(|value| parse_at(input, value.trim()))
+        .collect()
+}
+
+/// Parse the whitespace separated values in `text`, a slice of `input`, like `22 13 17 11  0`
+pub fn whitespace_separated<T>(input: &str, text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display + 'static,
+{
+    text.split_whitespace()
+        .map(|value| parse_at(input, value))
+        .collect()
+}
+
+/// Split `text`, a slice of `input`, around the first `separator`
+pub fn split_pair<'a>(
+    input: &str,
+    text: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {:?}", separator)))
+}
+
+/// Parse an `x,y` pair in `text`, a slice of `input`
+pub fn point(input: &str, text: &str) -> Result<Point, ParseError> {
+    let (x, y) = split_pair(input, text, ",")?;
+    Ok(Point::new(
+        parse_at(input, x.trim())?,
+        parse_at(input, y.trim())?,
+    ))
+}
+
+/// Parse a grid of single digits, one row per line, with `(0, 0)` the first digit of the first
+/// line
+pub fn digit_grid<T>(input: &str) -> Result<Grid<T>, ParseError>
+where
+    T: From<u8> + Clone + Default,
+{
+    if lines(input).next().is_none() {
+        return Err(ParseError::at(input, input, "expected a grid of digits"));
+    }
+
+    let mut grid = Grid::new_from_range(0..1, 0..1);
+    for (y, line) in lines(input).enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                ParseError::at(input, &line[i..i + c.len_utf8()], "expected a digit")
+            })?;
+            grid.set(x as isize, y as isize, T::from(digit as u8));
+        }
+    }
+
+    Ok(grid)
+}
+
+/// Used by `#[derive(FromLine)]`, match `text` against the `literals` of a pattern that has a
+/// field between each of them, returning the text of those fields
+pub fn match_pattern<'a>(text: &'a str, literals: &[&str]) -> Result<Vec<&'a str>, ParseError> {
+    let expected = |rest: &str, literal: &str| {
+        let message = match literal {
+            "" => "expected the end of the line".to_string(),
+            literal => format!("expected {:?}", literal),
+        };
+        ParseError::at(text, rest, message)
+    };
+
+    let (first, literals) = literals.split_first().unwrap_or((&"", &[]));
+    let mut rest = text
+        .strip_prefix(first)
+        .ok_or_else(|| expected(text, first))?;
+    let mut fields = vec![];
+    for (i, literal) in literals.iter().enumerate() {
+        let end = if i == literals.len() - 1 {
+            // the last literal has to end the line, even if it shows up earlier too
+            rest.ends_with(literal).then(|| rest.len() - literal.len())
+        } else {
+            rest.find(literal)
+        };
+        let end = end.ok_or_else(|| expected(&rest[rest.len()..], literal))?;
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(expected(rest, ""));
+    }
+
+    Ok(fields)
+}
+
+/// Used by `#[derive(FromLine)]`, parse the values in `text` separated by `separator`, or by
+/// whitespace if there isn't one, collecting them into a `Vec` or array
+pub fn parse_repeated<T, C>(
+    input: &str,
+    text: &str,
+    separator: Option<&str>,
+) -> Result<C, ParseError>
+where
+    T: FromStr,
+    T::Err: Display + 'static,
+    C: TryFrom<Vec<T>>,
+{
+    let values = match separator {
+        Some(separator) => text
+            .split(separator)
+            .map(|value| parse_at(input, value.trim()))
+            .collect::<Result<Vec<T>, _>>()?,
+        None => whitespace_separated(input, text)?,
+    };
+    let count = values.len();
+
+    C::try_from(values).map_err(|_| {
+        ParseError::at(
+            input,
+            text,
+            format!("wrong number of values, found {}", count),
+        )
+    })
+}
+
+/// Where `slice`, which must be part of `text`, starts in it
+fn offset(text: &str, slice: &str) -> usize {
+    slice.as_ptr() as usize - text.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_error_position() {
+        let input = "forward 5\ndown x5\nup 3";
+        let error = parse_at::<usize>(input, &input[15..17]).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.text, "x5");
+        assert_eq!(error.line_text, "down x5");
+        assert_eq!(
+            error.to_string(),
+            r#"parse error at line 2, column 6: invalid digit found in string (found "x5")
+    down x5
+         ^^"#
+        );
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(
+            lines("1\r\n2\n3\r\n\r\n").collect::<Vec<_>>(),
+            ["1", "2", "3"]
+        );
+        assert_eq!(lines("").count(), 0);
+
+        let input = "1\r\n2\r\nx\r\n";
+        let error = each_line(input, |line| parse_at::<usize>(input, line)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.line_text, "x");
+    }
+
+    #[test]
+    fn paragraphs() {
+        let input = "7,4,9\r\n\r\n22 13\r\n 8  2\n\n\n1 2\n";
+        let blocks = blocks(input).collect::<Vec<_>>();
+        assert_eq!(blocks, ["7,4,9", "22 13\r\n 8  2", "1 2"]);
+
+        assert_eq!(comma_separated::<u8>(input, blocks[0]), Ok(vec![7, 4, 9]));
+        let rows = lines(blocks[1])
+            .map(|row| whitespace_separated::<u8>(input, row))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(rows, Ok(vec![vec![22, 13], vec![8, 2]]));
+
+        let error = comma_separated::<u8>(input, "7,,9").unwrap_err();
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn points_and_grids() {
+        let input = "0,9 -> 5,9\n8,x -> 0,8";
+        let vents = each_line(input, |line| {
+            let (from, to) = split_pair(input, line, " -> ")?;
+            Ok((point(input, from)?, point(input, to)?))
+        });
+        let error = vents.unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+        assert_eq!(point("", "3,-4").map(|p| (p.x(), p.y())), Ok((3, -4)));
+        assert_eq!(
+            split_pair("", "5,9", " -> ").unwrap_err().message,
+            r#"expected " -> ""#
+        );
+
+        let input = "219\r\n398\r\n";
+        let grid = digit_grid::<usize>(input).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(*grid.get(2, 1), 8);
+        let error = digit_grid::<usize>("21\n3a").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = digit_grid::<usize>("\n").err().unwrap();
+        assert_eq!(error.message, "expected a grid of digits");
+    }
+
+    #[derive(Debug, PartialEq, FromLine)]
+    #[pattern("{name}: {values*,} | {pair*} {{{unit}}}")]
+    struct Record {
+        name: String,
+        values: Vec<u8>,
+        pair: [i32; 2],
+        unit: char,
+    }
+
+    #[derive(Debug, PartialEq, FromLine)]
+    enum Step {
+        #[pattern("fold along x={0}")]
+        X(isize),
+        #[pattern("on {x}..{y}")]
+        On { x: isize, y: isize },
+    }
+
+    #[test]
+    fn from_line() {
+        assert_eq!(
+            "abc: 1, 2,3 | -4  5 {m}".parse(),
+            Ok(Record {
+                name: "abc".to_string(),
+                values: vec![1, 2, 3],
+                pair: [-4, 5],
+                unit: 'm',
+            })
+        );
+        assert_eq!("fold along x=7".parse(), Ok(Step::X(7)));
+        assert_eq!("on -1..2".parse(), Ok(Step::On { x: -1, y: 2 }));
+
+        let input = "on 1..2\nfold along x=z";
+        let error = each_line(input, |line| parse_at::<Step>(input, line)).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 14, "z")
+        );
+
+        let error = "off 1..2".parse::<Step>().unwrap_err();
+        assert_eq!(
+            error.message,
+            r#"expected one of "fold along x={0}", "on {x}..{y}""#
+        );
+
+        let input = "abc: 1 | 2 3 4 {m}";
+        let error = parse_at::<Record>(input, input).unwrap_err();
+        assert_eq!(error.message, "wrong number of values, found 3");
+        assert_eq!((error.column, error.text.as_str()), (10, "2 3 4"));
+        let error = parse_at::<Record>(input, "abc: 1 | 2 3 {m").unwrap_err();
+        assert_eq!(error.message, r#"expected "}""#);
+    }
+}
@@ -0,0 +1,191 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display, Formatter};
+
+/// Counts the allocations made by each thread before handing them off to the system allocator.
+/// It's only installed as the global allocator with the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+struct Counts {
+    allocations: usize,
+    bytes: usize,
+    live: isize,
+    peak: isize,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn count(allocated: usize, freed: usize) {
+    // the thread local is gone while a thread is shutting down, we don't care about those
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated;
+        }
+        c.live += allocated as isize - freed as isize;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(0, layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// What a single run allocated on its thread
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// The most bytes that were live at once, above what was live when the run started
+    pub peak: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            bytes(self.bytes),
+            bytes(self.peak)
+        )
+    }
+}
+
+fn bytes(bytes: usize) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+/// Whether allocations are being counted, this is a compile time choice
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Counts allocations on this thread from when it's started
+pub struct Measurement {
+    before: Counts,
+}
+
+impl Measurement {
+    /// Start counting, if counting is enabled
+    pub fn start() -> Option<Measurement> {
+        if !enabled() {
+            return None;
+        }
+
+        let before = COUNTS.with(|counts| {
+            let mut c = counts.get();
+            c.peak = c.live;
+            counts.set(c);
+            c
+        });
+
+        Some(Measurement { before })
+    }
+
+    pub fn finish(self) -> AllocStats {
+        let after = COUNTS.with(Cell::get);
+
+        AllocStats {
+            allocations: after.allocations - self.before.allocations,
+            bytes: after.bytes - self.before.bytes,
+            peak: (after.peak - self.before.live).max(0) as usize,
+        }
+    }
+}
+
+/// Count the allocations made by `runner` on this thread, if counting is enabled
+pub fn measure<T, F>(runner: F) -> (T, Option<AllocStats>)
+where
+    F: FnOnce() -> T,
+{
+    let measurement = Measurement::start();
+    let result = runner();
+
+    (result, measurement.map(Measurement::finish))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let stats = AllocStats {
+            allocations: 3,
+            bytes: 3 << 20,
+            peak: 1536,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocs, 3.0 MiB allocated, 1.5 KiB peak"
+        );
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counts() {
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            second.len()
+        });
+
+        assert_eq!(
+            stats,
+            Some(AllocStats {
+                allocations: 2,
+                bytes: 1600,
+                peak: 1000,
+            })
+        );
+    }
+}
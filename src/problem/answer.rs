@@ -0,0 +1,141 @@
+use std::fmt::{self, Display, Formatter};
+use std::num::TryFromIntError;
+
+/// What a part found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Any integer, wide enough for the answers that overflow a `u64`
+    Number(i128),
+    Text(String),
+    /// A picture to read the answer from, like day 13's folded paper. Grids are compared line by
+    /// line, ignoring trailing whitespace and blank lines.
+    Grid(String),
+    /// The part ran but couldn't find an answer
+    NoAnswer,
+    /// The part hasn't been solved yet
+    NotImplemented,
+}
+
+impl Answer {
+    pub fn grid<S: Into<String>>(grid: S) -> Answer {
+        Answer::Grid(grid.into())
+    }
+
+    /// Whether this is the same answer as `expected`, which is how answers are written down in
+    /// examples and the answers file
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Number(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(text) => text.trim() == expected.trim(),
+            Answer::Grid(grid) => grid_lines(grid) == grid_lines(expected),
+            Answer::NoAnswer | Answer::NotImplemented => false,
+        }
+    }
+
+    /// The answer as we'd submit or record it, if there is one. A grid has to be read before it
+    /// can be submitted, so it's only for showing.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Answer::Grid(_) | Answer::NoAnswer | Answer::NotImplemented => None,
+            answer => Some(answer.to_string()),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+            Answer::Grid(_) => "grid",
+            Answer::NoAnswer => "no answer",
+            Answer::NotImplemented => "not implemented",
+        }
+    }
+}
+
+fn grid_lines(grid: &str) -> Vec<&str> {
+    let mut lines = grid.lines().map(str::trim_end).collect::<Vec<_>>();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    while lines.first() == Some(&"") {
+        lines.remove(0);
+    }
+    lines
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(grid) => write!(f, "{}", grid),
+            Answer::NoAnswer | Answer::NotImplemented => write!(f, "{}", self.kind()),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// Only up to `i128::MAX`, the rest don't fit in a `Number`
+impl TryFrom<u128> for Answer {
+    type Error = TryFromIntError;
+
+    fn try_from(n: u128) -> Result<Self, Self::Error> {
+        i128::try_from(n).map(Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// `None` is a part that couldn't find its answer
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::NoAnswer, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches() {
+        assert!(Answer::from(1640526601595u64).matches("1640526601595"));
+        assert!(Answer::from(-3).matches(" -3\n"));
+        assert!(!Answer::from(36).matches("365"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(Answer::grid("#..#  \n.##.\n\n").matches("\n#..#\n.##."));
+        assert!(!Answer::grid("#..#\n.##.").matches("#..#\n.#.#"));
+        assert!(!Answer::NoAnswer.matches("no answer"));
+        assert_eq!(Answer::from(None::<usize>), Answer::NoAnswer);
+        assert_eq!(Answer::NotImplemented.value(), None);
+        assert_eq!(Answer::grid("#..#").value(), None);
+        assert_eq!(
+            Answer::try_from(u64::MAX as u128 + 1),
+            Ok(Answer::Number(u64::MAX as i128 + 1))
+        );
+        assert!(Answer::try_from(u128::MAX).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// A panic caught by `catch`, with where it happened
+#[derive(Debug, Clone, PartialEq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Panic {}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Wrap the current panic hook so panics inside `catch` are recorded instead of printed, every
+/// other panic still goes to the original hook
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let caught = Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(|l| l.to_string()),
+                };
+                CAUGHT.with(|c| *c.borrow_mut() = Some(caught));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Run `runner`, turning a panic into an error so the rest of the run can carry on
+pub fn catch<T, F>(runner: F) -> Result<T, Panic>
+where
+    F: FnOnce() -> T,
+{
+    install_hook();
+
+    let was_catching = CATCHING.with(|c| c.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(runner));
+    CATCHING.with(|c| c.set(was_catching));
+
+    result.map_err(|payload| {
+        CAUGHT
+            .with(|c| c.borrow_mut().take())
+            .unwrap_or_else(|| Panic {
+                message: payload_message(&*payload),
+                location: None,
+            })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn catches() {
+        assert_eq!(catch(|| 5), Ok(5));
+
+        let line = line!() + 1;
+        let caught = catch(|| -> usize { panic!("bad input {}", 5) }).unwrap_err();
+        assert_eq!(caught.message, "bad input 5");
+        assert!(caught
+            .location
+            .unwrap()
+            .starts_with(&format!("{}:{}:", file!(), line)));
+    }
+}
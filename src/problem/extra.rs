@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use clap::{Args, Command, FromArgMatches};
+use toml::Value;
+
+use crate::toml_file::{TomlFile, TomlFileError};
+
+pub static DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+
+/// A problem's `Extra` settings, which can be changed with command line style arguments like
+/// `--days 1000`. Its `Default` is what the puzzle asks for.
+pub trait Configurable: Sized + Default + Clone + PartialEq + Send + Sync + 'static {
+    fn from_args(args: &[String]) -> Result<Self, String>;
+}
+
+/// Problems without any settings
+impl Configurable for () {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        match args.first() {
+            None => Ok(()),
+            Some(arg) => Err(format!("unexpected {:?}, there's nothing to set", arg)),
+        }
+    }
+}
+
+/// Make a type deriving `clap::Args` `Configurable`, using the defaults of its arguments as its
+/// `Default`
+///
+/// ```ignore
+/// #[derive(Debug, Clone, PartialEq, Args)]
+/// pub(crate) struct Breeding {
+///     /// Days to breed for in both parts
+///     #[arg(long)]
+///     days: Option<usize>,
+/// }
+///
+/// configurable!(Breeding);
+/// ```
+#[macro_export]
+macro_rules! configurable {
+    ($extra:ty) => {
+        impl $crate::problem::extra::Configurable for $extra {
+            fn from_args(args: &[String]) -> Result<Self, String> {
+                $crate::problem::extra::parse_args(args)
+            }
+        }
+
+        impl Default for $extra {
+            fn default() -> Self {
+                $crate::problem::extra::parse_args(&[]).expect("the default settings should parse")
+            }
+        }
+    };
+}
+
+/// Parse `args` into `T` with clap, later arguments overriding earlier ones
+pub fn parse_args<T: Args + FromArgMatches>(args: &[String]) -> Result<T, String> {
+    let command = T::augment_args(
+        Command::new("settings")
+            .no_binary_name(true)
+            .disable_help_flag(true)
+            .args_override_self(true),
+    );
+
+    command
+        .try_get_matches_from(args)
+        .and_then(|matches| T::from_arg_matches(&matches))
+        .map_err(|e| {
+            e.to_string()
+                .trim_start_matches("error: ")
+                .trim()
+                .to_string()
+        })
+}
+
+/// The arguments for each day's `Extra`, from a config file with a table per day and from the
+/// command line:
+///
+/// ```toml
+/// [6]
+/// days = 1000
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExtraArgs {
+    days: HashMap<usize, Vec<String>>,
+}
+
+impl ExtraArgs {
+    /// Load the settings in `path`, there aren't any if it doesn't exist
+    pub fn load(path: &Path) -> Result<ExtraArgs, TomlFileError> {
+        let file = TomlFile::new("config", path);
+        let invalid = |message: String| file.invalid(message);
+        let table = file.load()?;
+
+        let mut days = HashMap::new();
+        for (day, settings) in table {
+            let number = day
+                .parse::<usize>()
+                .map_err(|_| invalid(format!("[{}] should be a day number", day)))?;
+            let settings = settings
+                .as_table()
+                .ok_or_else(|| invalid(format!("[{}] should be a table of settings", day)))?;
+
+            let mut args = vec![];
+            for (name, value) in settings {
+                to_args(name, value, &mut args)
+                    .map_err(|message| invalid(format!("[{}] {}", day, message)))?;
+            }
+            days.insert(number, args);
+        }
+
+        Ok(ExtraArgs { days })
+    }
+
+    /// Add command line arguments for `day`, which override any from the config file
+    pub fn with_args(mut self, day: usize, args: &[String]) -> ExtraArgs {
+        self.days.entry(day).or_default().extend_from_slice(args);
+        self
+    }
+
+    pub fn get(&self, day: usize) -> &[String] {
+        self.days.get(&day).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// `name = value` as arguments, so `days = 1000` is `--days 1000` and `full = true` is `--full`
+fn to_args(name: &str, value: &Value, args: &mut Vec<String>) -> Result<(), String> {
+    let flag = format!("--{}", name.replace('_', "-"));
+    match value {
+        Value::Boolean(true) => args.push(flag),
+        Value::Boolean(false) => {}
+        Value::String(value) => args.extend([flag, value.clone()]),
+        Value::Integer(_) | Value::Float(_) => args.extend([flag, value.to_string()]),
+        Value::Array(values) => {
+            for value in values {
+                to_args(name, value, args)?;
+            }
+        }
+        _ => return Err(format!("{} should be a boolean, number or string", name)),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[derive(Debug, Clone, PartialEq, Args)]
+    struct Settings {
+        #[arg(long, default_value_t = 3)]
+        window: usize,
+        #[arg(long)]
+        days: Option<usize>,
+    }
+
+    configurable!(Settings);
+
+    #[test]
+    fn settings() {
+        let dir = TempDir::new("config");
+        let path = dir.join("aoc.toml");
+        fs::write(&path, "[6]\ndays = 1000\nwindow = 5\n").unwrap();
+
+        let args = ExtraArgs::load(&path)
+            .unwrap()
+            .with_args(6, &["--window".to_string(), "4".to_string()]);
+        let settings = Settings::from_args(args.get(6)).unwrap();
+        assert_eq!((settings.window, settings.days), (4, Some(1000)));
+        assert_eq!(Settings::default().window, 3);
+        assert_eq!(
+            Settings::from_args(&["--window".to_string(), "3".to_string()]).unwrap(),
+            Settings::default()
+        );
+        assert!(args.get(1).is_empty());
+
+        let error = Settings::from_args(&["--windows".to_string()]).unwrap_err();
+        assert!(
+            error.starts_with("unexpected argument '--windows'"),
+            "{}",
+            error
+        );
+        assert!(<()>::from_args(&["--days".to_string()]).is_err());
+
+        fs::write(&path, "[six]\ndays = 1000\n").unwrap();
+        assert!(ExtraArgs::load(&path).is_err());
+        assert!(ExtraArgs::load(&dir.join("missing.toml")).is_ok());
+    }
+}
@@ -0,0 +1,1227 @@
+use crate::animation::{RecordOptions, Recorder};
+use crate::answers::{Answers, Check};
+use crate::bench;
+use crate::bench::baseline::{Baseline, Comparison};
+use crate::bench::BenchOptions;
+use crate::input::normalise::normalise;
+use crate::input::InputSource;
+pub use crate::parse::{parse_at, ParseError};
+pub use crate::problem::answer::Answer;
+use crate::problem::catch::catch;
+use crate::problem::extra::{Configurable, ExtraArgs};
+use crate::problem::timeout::{with_lap_timeout, CancelToken, LapTimer};
+use crate::report::{Format, PartReport, Reporter, Timing};
+use crate::submit::{Submitter, Verdict};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub mod alloc;
+pub mod answer;
+pub mod catch;
+pub mod extra;
+pub mod timeout;
+
+pub struct ProblemState<T: Sized + Default> {
+    pub name: String,
+    pub is_example: bool,
+    pub extra: T,
+    /// Cancelled once a part runs past `RunOptions::timeout`, slow parts should check it and
+    /// give up early
+    pub cancel: CancelToken,
+    /// Frames of the part's progress, only kept when `RunOptions::frames` is set
+    pub frames: Recorder,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum RunFor {
+    Part1,
+    Part2,
+    #[default]
+    Both,
+    /// For `Problem::PARTS` while a day hasn't been solved yet, so it isn't run
+    Neither,
+}
+
+impl RunFor {
+    /// Narrow `self` down to the parts that are also allowed by `filter`
+    pub fn restrict(self, filter: RunFor) -> Option<RunFor> {
+        match (self, filter) {
+            (RunFor::Neither, _) | (_, RunFor::Neither) => None,
+            (run_for, RunFor::Both) => Some(run_for),
+            (RunFor::Both, filter) => Some(filter),
+            (run_for, filter) if run_for == filter => Some(run_for),
+            _ => None,
+        }
+    }
+}
+
+impl RunFor {
+    pub fn parts(self) -> &'static [usize] {
+        match self {
+            RunFor::Part1 => &[1],
+            RunFor::Part2 => &[2],
+            RunFor::Both => &[1, 2],
+            RunFor::Neither => &[],
+        }
+    }
+}
+
+impl FromStr for RunFor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(RunFor::Part1),
+            "2" => Ok(RunFor::Part2),
+            "both" => Ok(RunFor::Both),
+            _ => Err(format!("expected 1, 2 or both, found: {}", s)),
+        }
+    }
+}
+
+/// Settings shared by every problem in a run
+#[derive(Clone)]
+pub struct RunOptions {
+    /// Only run the parts allowed by this, including for examples
+    pub run_for: RunFor,
+    pub input: InputSource,
+    /// Submit the answers for the real input if this is set
+    pub submitter: Option<Submitter>,
+    /// Sample the real input's parse and parts repeatedly instead of timing them once. Examples are
+    /// skipped while benchmarking.
+    pub bench: Option<BenchOptions>,
+    pub reporter: Arc<dyn Reporter>,
+    /// Check the real input's answers against these, recording new ones if they're set to
+    pub answers: Option<Arc<Answers>>,
+    /// Compare the real input's part times against these
+    pub baseline: Option<Arc<Baseline>>,
+    /// Give up on any part that runs for longer than this. When benchmarking it's the limit for
+    /// each sample.
+    pub timeout: Option<Duration>,
+    /// Change the real input's `Problem::Extra` with these. Its answers aren't checked, submitted
+    /// or compared to the baseline once it has been changed.
+    pub extra_args: Option<Arc<ExtraArgs>>,
+    /// Save the frames each part of the real input records as an animation. Nothing is recorded
+    /// while benchmarking.
+    pub frames: Option<RecordOptions>,
+    /// Remove blank lines from the end of inputs before parsing them. Byte order marks and CRLF
+    /// line endings are always removed.
+    pub trim_trailing_lines: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            run_for: RunFor::default(),
+            input: InputSource::default(),
+            submitter: None,
+            bench: None,
+            reporter: Format::default().reporter(),
+            answers: None,
+            baseline: None,
+            timeout: None,
+            extra_args: None,
+            frames: None,
+            trim_trailing_lines: true,
+        }
+    }
+}
+
+/// An example from the puzzle description along with the answers it should produce
+pub struct Example<E> {
+    pub input: &'static str,
+    pub extra: E,
+    pub run_for: RunFor,
+    pub expected_1: Option<&'static str>,
+    pub expected_2: Option<&'static str>,
+}
+
+impl<E: Default> Example<E> {
+    pub fn new(input: &'static str, expected_1: &'static str, expected_2: &'static str) -> Self {
+        Example {
+            input,
+            extra: E::default(),
+            run_for: RunFor::Both,
+            expected_1: Some(expected_1),
+            expected_2: Some(expected_2),
+        }
+    }
+
+    pub fn part_1(input: &'static str, expected: &'static str) -> Self {
+        Example {
+            input,
+            extra: E::default(),
+            run_for: RunFor::Part1,
+            expected_1: Some(expected),
+            expected_2: None,
+        }
+    }
+
+    pub fn part_2(input: &'static str, expected: &'static str) -> Self {
+        Example {
+            input,
+            extra: E::default(),
+            run_for: RunFor::Part2,
+            expected_1: None,
+            expected_2: Some(expected),
+        }
+    }
+
+    pub fn with_extra(mut self, extra: E) -> Self {
+        self.extra = extra;
+        self
+    }
+}
+
+pub trait Problem {
+    type Input: Send + Sync + 'static;
+    /// Settings for the problem, see `extra::Configurable`
+    type Extra: Configurable;
+
+    fn parse(s: &str, state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError>;
+    fn part_1(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::NotImplemented
+    }
+
+    fn part_2(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::NotImplemented
+    }
+
+    /// The day of the puzzle, see `days::DAYS` for where it's checked
+    const DAY: usize;
+    /// Which parts have been solved so far
+    const PARTS: RunFor = RunFor::Both;
+
+    /// Solve both parts in one go so part 2 can reuse part 1's work. Call `laps.part_1` as soon
+    /// as part 1's answer is known so each part is still timed separately. This is used whenever
+    /// both parts run, except when benchmarking.
+    fn solve(input: &Self::Input, state: &ProblemState<Self::Extra>, laps: &mut Laps) {
+        laps.part_1(Self::part_1(input, state));
+        laps.part_2(Self::part_2(input, state));
+    }
+
+    /// Examples that are checked before the real input is run, and by the tests from `problem_tests!`
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![]
+    }
+}
+
+/// Records each answer from `Problem::solve` along with how long, and how many allocations, it
+/// took since the last one. The answers are shared so any that were found before a timeout
+/// aren't lost.
+pub struct Laps {
+    timer: LapTimer,
+    allocations: Option<alloc::Measurement>,
+    answers: LapAnswers,
+    /// Answers that come in after this is cancelled are ignored, the part ran out of time
+    cancel: CancelToken,
+}
+
+type LapAnswers = Arc<Mutex<[Option<(Answer, Timing)>; 2]>>;
+
+impl Laps {
+    fn new(cancel: CancelToken) -> Laps {
+        Laps {
+            cancel,
+            timer: LapTimer::new(),
+            allocations: alloc::Measurement::start(),
+            answers: Arc::new(Mutex::new([None, None])),
+        }
+    }
+
+    /// Start timing the first lap, on the thread that's going to run it as allocations are
+    /// counted per thread
+    fn start(&mut self) {
+        self.timer.lap();
+        self.allocations = alloc::Measurement::start();
+    }
+
+    pub fn part_1<A: Into<Answer>>(&mut self, answer: A) {
+        self.lap(1, answer);
+    }
+
+    pub fn part_2<A: Into<Answer>>(&mut self, answer: A) {
+        self.lap(2, answer);
+    }
+
+    fn lap<A: Into<Answer>>(&mut self, part: usize, answer: A) {
+        let timing = Timing {
+            elapsed: self.timer.lap(),
+            stats: None,
+            allocations: self.allocations.take().map(alloc::Measurement::finish),
+        };
+        if !self.cancel.is_cancelled() {
+            self.answers.lock().unwrap()[part - 1] = Some((answer.into(), timing));
+        }
+
+        self.allocations = alloc::Measurement::start();
+    }
+}
+
+/// Check the problem's examples, then run it against its input, loaded at runtime from
+/// `options.input`. We stop at the first example that doesn't produce its expected answers.
+pub fn run<P: Problem>(options: &RunOptions, extra: P::Extra) {
+    let run_for = match P::PARTS.restrict(options.run_for) {
+        Some(run_for) => run_for,
+        None => return,
+    };
+
+    for (i, example) in P::examples().into_iter().enumerate() {
+        if let Some(run_for) = example.run_for.restrict(run_for) {
+            let expected = [example.expected_1, example.expected_2];
+            let name = (i + 1).to_string();
+            if !run_with_name::<P>(
+                &name,
+                true,
+                run_for,
+                example.extra,
+                example.input,
+                expected,
+                options,
+            ) {
+                options.reporter.message(&format!(
+                    "{} Example {} failed, skipping the problem",
+                    P::DAY,
+                    name
+                ));
+                return;
+            }
+        }
+    }
+
+    let name = format!("{} Problem", P::DAY);
+    let args = options
+        .extra_args
+        .as_ref()
+        .map_or(&[][..], |extra_args| extra_args.get(P::DAY));
+    let (extra, options) = if args.is_empty() {
+        (extra, Cow::Borrowed(options))
+    } else {
+        match P::Extra::from_args(args) {
+            // settings that only restate the defaults don't change the answers
+            Ok(extra) if extra == P::Extra::default() => (extra, Cow::Borrowed(options)),
+            Ok(extra) => {
+                options.reporter.message(&format!(
+                    "{} with {}, answers won't be checked or submitted",
+                    name,
+                    args.join(" ")
+                ));
+                let options = RunOptions {
+                    submitter: None,
+                    answers: None,
+                    baseline: None,
+                    ..options.clone()
+                };
+                (extra, Cow::Owned(options))
+            }
+            Err(e) => {
+                let e = format!("bad settings: {}", e);
+                let reports = failed_reports::<P>(run_for, &name, false, None, &e);
+                options.reporter.failed(&name, &e, &reports);
+                return;
+            }
+        }
+    };
+
+    match options.input.load(P::DAY) {
+        Ok(input) => {
+            run_with_name::<P>(" ", false, run_for, extra, &input, [None, None], &options);
+        }
+        Err(e) => {
+            let reports = failed_reports::<P>(run_for, &name, false, None, &e);
+            options.reporter.failed(&name, &e.to_string(), &reports);
+        }
+    }
+}
+
+/// Generates a test suite for a problem, with a module of tests for each of its examples and a
+/// module for its real input. Every part gets its own test so one failing part can't hide another.
+///
+/// ```ignore
+/// problem_tests!(Eight, ();
+///     example_1[part_2], example_2[part_1, part_2];
+///     part_1 => "365", part_2 => "975706"
+/// );
+/// ```
+///
+/// Examples are matched up with `Problem::examples` by position. Attributes like `#[ignore]`
+/// before an example are added to each of its tests.
+#[macro_export]
+macro_rules! problem_tests {
+    ( $problem:ty, $extra:expr;
+      $( $( #[$attr:meta] )* $example:ident [ $( $example_part:ident ),+ ] ),* ;
+      $( $part:ident => $expected:expr ),* $(,)? ) => {
+        $crate::problem_tests!(
+            @examples $problem; 0usize;
+            $( { $( #[$attr] )* } $example [ $( $example_part ),+ ] ),*
+        );
+
+        #[test]
+        fn all_examples_tested() {
+            let tested: &[&str] = &[$( stringify!($example) ),*];
+            assert_eq!(
+                <$problem as $crate::problem::Problem>::examples().len(),
+                tested.len(),
+                "every example should be tested"
+            );
+        }
+
+        $crate::problem_tests!(@input $problem, $extra; $( $part => $expected ),*);
+    };
+    (@input $problem:ty, $extra:expr; ) => {};
+    (@input $problem:ty, $extra:expr; $( $part:ident => $expected:expr ),+ ) => {
+        mod input {
+            use super::*;
+            $(
+                #[test]
+                fn $part() {
+                    $crate::problem::assert_part::<$problem>(
+                        $extra,
+                        $crate::problem_tests!(@part $part),
+                        $expected,
+                    );
+                }
+            )+
+        }
+    };
+    (@examples $problem:ty; $index:expr; ) => {};
+    (@examples $problem:ty; $index:expr;
+      $attrs:tt $example:ident [ $( $example_part:ident ),+ ] $(, $( $rest:tt )* )? ) => {
+        mod $example {
+            use super::*;
+            $(
+                $crate::problem_tests!(@example $attrs $problem; $index; $example_part);
+            )+
+        }
+        $crate::problem_tests!(@examples $problem; $index + 1; $( $( $rest )* )?);
+    };
+    (@example { $( #[$attr:meta] )* } $problem:ty; $index:expr; $example_part:ident) => {
+        #[test]
+        $( #[$attr] )*
+        fn $example_part() {
+            $crate::problem::assert_example::<$problem>(
+                $index,
+                $crate::problem_tests!(@part $example_part),
+            );
+        }
+    };
+    (@part part_1) => { 1 };
+    (@part part_2) => { 2 };
+}
+
+/// Check a single part of the example at `index` in `Problem::examples` against its expected answer
+pub fn assert_example<P: Problem>(index: usize, part: usize) {
+    let example = P::examples()
+        .into_iter()
+        .nth(index)
+        .unwrap_or_else(|| panic!("{} has no example {}", P::DAY, index + 1));
+    let expected = [example.expected_1, example.expected_2][part - 1]
+        .unwrap_or_else(|| panic!("Example {} has no answer for part {}", index + 1, part));
+
+    let state = ProblemState {
+        name: format!("{}.{} Example {}", P::DAY, part, index + 1),
+        is_example: true,
+        extra: example.extra,
+        cancel: CancelToken::default(),
+        frames: Recorder::default(),
+    };
+    let (raw_input, _) = normalise(example.input, true);
+    let input = P::parse(&raw_input, &state).unwrap_or_else(|e| panic!("{}:\n{}", state.name, e));
+
+    assert_answer(
+        &solve_part::<P>(part, &input, &state),
+        expected,
+        &state.name,
+    );
+    if example.run_for == RunFor::Both {
+        assert_solve::<P>(part, &input, &state, expected);
+    }
+}
+
+/// Check a single part against the problem's input from the default inputs directory
+pub fn assert_part<P: Problem>(extra: P::Extra, part: usize, expected: &str) {
+    let s = InputSource::default()
+        .load(P::DAY)
+        .unwrap_or_else(|e| panic!("{}", e));
+    let state = ProblemState {
+        name: format!("{}.{} Problem", P::DAY, part),
+        is_example: false,
+        extra,
+        cancel: CancelToken::default(),
+        frames: Recorder::default(),
+    };
+    let (s, _) = normalise(&s, true);
+    let input = P::parse(&s, &state).unwrap_or_else(|e| panic!("{}:\n{}", state.name, e));
+
+    assert_answer(
+        &solve_part::<P>(part, &input, &state),
+        expected,
+        &state.name,
+    );
+    assert_solve::<P>(part, &input, &state, expected);
+}
+
+fn assert_answer(answer: &Answer, expected: &str, name: &str) {
+    assert!(
+        answer.matches(expected),
+        "{} answered {:?}, expected {:?}",
+        name,
+        answer,
+        expected
+    );
+}
+
+/// `Problem::solve` has to agree with the separate parts
+fn assert_solve<P: Problem>(
+    part: usize,
+    input: &P::Input,
+    state: &ProblemState<P::Extra>,
+    expected: &str,
+) {
+    let mut laps = Laps::new(CancelToken::default());
+    P::solve(input, state, &mut laps);
+
+    let answer = laps.answers.lock().unwrap()[part - 1]
+        .take()
+        .map_or(Answer::NoAnswer, |(answer, _)| answer);
+    assert_answer(&answer, expected, &format!("{} from solve", state.name));
+}
+
+fn solve_part<P: Problem>(part: usize, input: &P::Input, state: &ProblemState<P::Extra>) -> Answer {
+    match part {
+        1 => P::part_1(input, state),
+        2 => P::part_2(input, state),
+        _ => panic!("There is no part {}", part),
+    }
+}
+
+/// Run and report on a single input, checking any answers we expect. Returns false if the input
+/// couldn't be parsed or an answer didn't match what we expected.
+pub fn run_with_name<P: Problem>(
+    name: &str,
+    is_example: bool,
+    run_for: RunFor,
+    extra: P::Extra,
+    raw_input: &str,
+    expected: [Option<&str>; 2],
+    options: &RunOptions,
+) -> bool {
+    if is_example && options.bench.is_some() {
+        return true;
+    }
+
+    let problem_type = if !is_example { "Problem" } else { "Example" };
+
+    let state = ProblemState {
+        name: format!("{} {} {}", P::DAY, problem_type, name),
+        is_example,
+        extra,
+        cancel: CancelToken::default(),
+        frames: Recorder::default(),
+    };
+
+    let (raw_input, changes) = normalise(raw_input, options.trim_trailing_lines);
+    if !changes.is_empty() {
+        let changes = changes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        options.reporter.message(&format!(
+            "{} input: {}",
+            state.name.trim_end(),
+            changes.join(", ")
+        ));
+    }
+    let raw_input = raw_input.as_ref();
+
+    let (parsed, parse_time) = match catch(|| time(options, || P::parse(raw_input, &state))) {
+        Ok((parsed, parse_time)) => {
+            options.reporter.parsed(P::DAY, &state.name, &parse_time);
+            (parsed.map_err(|e| e.to_string()), Some(parse_time))
+        }
+        Err(panic) => (Err(panic.to_string()), None),
+    };
+
+    let parts: [(usize, PartFn<P>); 2] = [(1, P::part_1), (2, P::part_2)];
+    let run_parts = parts
+        .into_iter()
+        .filter(|(part, _)| run_for.parts().contains(part))
+        .collect::<Vec<_>>();
+    let input = match parsed {
+        Ok(input) => Arc::new(input),
+        Err(e) => {
+            let reports = failed_reports::<P>(run_for, &state.name, is_example, parse_time, &e);
+            options.reporter.failed(&state.name, &e, &reports);
+            return false;
+        }
+    };
+
+    let part_name = |part: usize| format!("{}.{} {} {}", P::DAY, part, problem_type, name);
+    let report = |part: usize, solved: Result<(Answer, Timing), String>| {
+        report_part::<P>(
+            options,
+            &part_name(part),
+            is_example,
+            part,
+            raw_input,
+            expected[part - 1],
+            &parse_time,
+            solved,
+        )
+    };
+    let recorder = || match (&options.frames, &options.bench) {
+        (Some(_), None) if !is_example => Recorder::new(),
+        _ => Recorder::default(),
+    };
+    let solve_part = |part: usize, part_fn: PartFn<P>| {
+        let state = Arc::new(ProblemState {
+            name: part_name(part),
+            is_example,
+            extra: state.extra.clone(),
+            cancel: CancelToken::default(),
+            frames: recorder(),
+        });
+
+        // every run starts a new lap, so when benchmarking the time limit is for each sample
+        // rather than all of them
+        let timer = LapTimer::new();
+        let solve = {
+            let (input, state, options) = (input.clone(), state.clone(), options.clone());
+            let (timer, timed) = (timer.clone(), options.timeout.is_some());
+            move || {
+                catch(|| {
+                    time(&options, || {
+                        if timed {
+                            timer.lap();
+                        }
+                        part_fn(&input, &state)
+                    })
+                })
+            }
+        };
+        let solved = match options.timeout {
+            Some(limit) => {
+                with_lap_timeout(limit, &timer, &state.cancel, solve).map_err(|e| e.to_string())
+            }
+            None => Ok(solve()),
+        };
+        save_frames(options, &state.frames, &format!("{}.{}.png", P::DAY, part));
+
+        solved.and_then(|r| r.map_err(|e| e.to_string()))
+    };
+    let mut matched = true;
+
+    if run_for == RunFor::Both && options.bench.is_none() {
+        let joint_state = Arc::new(ProblemState {
+            name: state.name.clone(),
+            is_example,
+            extra: state.extra.clone(),
+            cancel: CancelToken::default(),
+            frames: recorder(),
+        });
+        let laps = Laps::new(joint_state.cancel.clone());
+        let (timer, answers) = (laps.timer.clone(), laps.answers.clone());
+        let solve = {
+            let (input, state) = (input.clone(), joint_state.clone());
+            move || {
+                let mut laps = laps;
+                laps.start();
+                catch(|| P::solve(&input, &state, &mut laps))
+            }
+        };
+        // each part gets the time limit from the end of the one before it
+        let solved = match options.timeout {
+            Some(limit) => with_lap_timeout(limit, &timer, &joint_state.cancel, solve)
+                .map_err(|e| e.to_string()),
+            None => Ok(solve()),
+        };
+        save_frames(options, &joint_state.frames, &format!("{}.png", P::DAY));
+
+        for &(part, part_fn) in &run_parts {
+            let answer = answers.lock().unwrap()[part - 1].take();
+            let solved = match (answer, &solved) {
+                (Some(answer), _) => Ok(answer),
+                // a panic might have stopped us before this part, so give it its own run
+                (None, Ok(Err(_))) => solve_part(part, part_fn),
+                (None, Ok(Ok(()))) => Err(format!("solve didn't give an answer for part {}", part)),
+                (None, Err(e)) => Err(e.clone()),
+            };
+            matched &= report(part, solved);
+        }
+
+        return matched;
+    }
+
+    for &(part, part_fn) in &run_parts {
+        matched &= report(part, solve_part(part, part_fn));
+    }
+
+    matched
+}
+
+/// Write any frames `frames` recorded to `file` in the frames directory
+fn save_frames(options: &RunOptions, frames: &Recorder, file: &str) {
+    let record = match &options.frames {
+        Some(record) if !frames.is_empty() => record,
+        _ => return,
+    };
+
+    let path = record.dir.join(file);
+    let message = match frames.write_png(&path, record.delay) {
+        Ok(()) => format!("Recorded {} frames to {}", frames.len(), path.display()),
+        Err(e) => e.to_string(),
+    };
+    options.reporter.message(&message);
+}
+
+/// Check and report a single part's answer, returning false if it failed or wasn't what we
+/// expected
+#[allow(clippy::too_many_arguments)]
+fn report_part<P: Problem>(
+    options: &RunOptions,
+    name: &str,
+    is_example: bool,
+    part: usize,
+    raw_input: &str,
+    expected: Option<&str>,
+    parse_time: &Option<Timing>,
+    solved: Result<(Answer, Timing), String>,
+) -> bool {
+    let (answer, solve_time) = match solved {
+        Ok(solved) => solved,
+        Err(e) => {
+            options.reporter.part(&PartReport {
+                day: P::DAY,
+                part,
+                name: name.to_string(),
+                is_example,
+                answer: None,
+                parse_time: parse_time.clone(),
+                solve_time: None,
+                error: Some(e),
+                check: None,
+                baseline: None,
+            });
+            return false;
+        }
+    };
+
+    let check = check_answer::<P>(options, is_example, part, raw_input, &answer);
+    let known_wrong = matches!(check, Some(Check::Wrong { .. }));
+    if answer == Answer::NotImplemented && !known_wrong {
+        options.reporter.part(&PartReport {
+            day: P::DAY,
+            part,
+            name: name.to_string(),
+            is_example,
+            answer: Some(answer),
+            parse_time: parse_time.clone(),
+            solve_time: Some(solve_time),
+            error: None,
+            check: None,
+            baseline: None,
+        });
+        return true;
+    }
+
+    let baseline = compare_baseline::<P>(options, is_example, part, &solve_time);
+    let expected = match &check {
+        Some(Check::Wrong { expected }) => Some(expected.as_str()),
+        _ => expected,
+    };
+    let error = match expected {
+        Some(expected) if matches!(answer, Answer::NoAnswer | Answer::NotImplemented) => Some(
+            format!("expected {:?}, got nothing ({})", expected, answer.kind()),
+        ),
+        Some(expected) if !answer.matches(expected) => Some(format!(
+            "wrong answer {:?}, expected {:?}",
+            answer.to_string(),
+            expected
+        )),
+        _ => None,
+    };
+    let matched = error.is_none();
+    // there's no point asking about an answer we already know is right or wrong
+    let known = matches!(check, Some(Check::Correct | Check::Wrong { .. }));
+
+    options.reporter.part(&PartReport {
+        day: P::DAY,
+        part,
+        name: name.to_string(),
+        is_example,
+        answer: Some(answer.clone()),
+        parse_time: parse_time.clone(),
+        solve_time: Some(solve_time),
+        error,
+        check,
+        baseline,
+    });
+    if !known {
+        submit::<P>(options, is_example, part, raw_input, answer);
+    }
+
+    matched
+}
+
+/// Build reports for every part we couldn't run, named like `5.1 Problem` from a `5 Problem` name
+fn failed_reports<P: Problem>(
+    run_for: RunFor,
+    name: &str,
+    is_example: bool,
+    parse_time: Option<Timing>,
+    error: &dyn Display,
+) -> Vec<PartReport> {
+    let day = P::DAY;
+    run_for
+        .parts()
+        .iter()
+        .map(|&part| PartReport {
+            day,
+            part,
+            name: name.replacen(&day.to_string(), &format!("{}.{}", day, part), 1),
+            is_example,
+            answer: None,
+            parse_time: parse_time.clone(),
+            solve_time: None,
+            error: Some(error.to_string()),
+            check: None,
+            baseline: None,
+        })
+        .collect()
+}
+
+type PartFn<P> = fn(&<P as Problem>::Input, &ProblemState<<P as Problem>::Extra>) -> Answer;
+
+/// Compare a real input's answer against the known answers, if we have any
+fn check_answer<P: Problem>(
+    options: &RunOptions,
+    is_example: bool,
+    part: usize,
+    raw_input: &str,
+    answer: &Answer,
+) -> Option<Check> {
+    let answers = match (&options.answers, is_example) {
+        (Some(answers), false) => answers,
+        _ => return None,
+    };
+
+    match answers.check(P::DAY, part, raw_input, answer) {
+        Ok(check) => Some(check),
+        Err(e) => {
+            options.reporter.message(&e.to_string());
+            None
+        }
+    }
+}
+
+/// Compare a real input's part time against the baseline, if we have one
+fn compare_baseline<P: Problem>(
+    options: &RunOptions,
+    is_example: bool,
+    part: usize,
+    solve_time: &Timing,
+) -> Option<Comparison> {
+    match (&options.baseline, is_example, &solve_time.stats) {
+        (Some(baseline), false, Some(stats)) => match baseline.compare(P::DAY, part, stats) {
+            Ok(comparison) => comparison,
+            Err(e) => {
+                options.reporter.message(&e.to_string());
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+fn submit<P: Problem>(
+    options: &RunOptions,
+    is_example: bool,
+    part: usize,
+    raw_input: &str,
+    answer: Answer,
+) {
+    if let (Some(submitter), Some(answer), false) = (&options.submitter, answer.value(), is_example)
+    {
+        let message = match submitter.submit(P::DAY, part, &answer) {
+            Ok(verdict) => {
+                if let (Verdict::Correct, Some(answers)) = (&verdict, &options.answers) {
+                    if let Err(e) = answers.record(P::DAY, part, raw_input, &answer) {
+                        options.reporter.message(&e.to_string());
+                    }
+                }
+                format!("Submitted {}: {}", answer, verdict)
+            }
+            Err(e) => format!("Submitting {}: {}", answer, e),
+        };
+        options.reporter.message(&message);
+    }
+}
+
+/// Time a single run of `runner`, or benchmark it if we're in bench mode, counting its
+/// allocations if that's enabled
+fn time<T, C>(options: &RunOptions, mut runner: C) -> (T, Timing)
+where
+    C: FnMut() -> T,
+{
+    match &options.bench {
+        Some(bench) => {
+            let allocations = if alloc::enabled() {
+                alloc::measure(&mut runner).1
+            } else {
+                None
+            };
+            let (result, stats) = bench::measure(bench, runner);
+            let timing = Timing {
+                elapsed: stats.median,
+                stats: Some(stats),
+                allocations,
+            };
+            (result, timing)
+        }
+        None => {
+            let ((result, elapsed), allocations) = alloc::measure(|| {
+                let now = Instant::now();
+                let result = runner();
+                (result, now.elapsed())
+            });
+            let timing = Timing {
+                elapsed,
+                stats: None,
+                allocations,
+            };
+            (result, timing)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::test_server::TestServer;
+
+    struct Double {}
+
+    impl Problem for Double {
+        type Input = usize;
+        type Extra = ();
+
+        const DAY: usize = 25;
+
+        fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+            parse_at(s, s)
+        }
+
+        fn part_1(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            (input * 2).into()
+        }
+
+        fn part_2(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            (input * 4).into()
+        }
+
+        fn examples() -> Vec<Example<Self::Extra>> {
+            vec![
+                Example::part_1("1", "2"),
+                Example::new("2", "4", "9"),
+                Example::new("3", "6", "12"),
+            ]
+        }
+    }
+
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<PartReport>>);
+
+    impl Reporter for Collect {
+        fn part(&self, report: &PartReport) {
+            self.0.lock().unwrap().push(report.clone());
+        }
+    }
+
+    #[test]
+    fn stops_on_wrong_example() {
+        let reporter = Arc::new(Collect::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            ..Default::default()
+        };
+        run::<Double>(&options, ());
+
+        let reports = reporter.0.lock().unwrap();
+        let names = reports.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["25.1 Example 1", "25.1 Example 2", "25.2 Example 2"]
+        );
+        assert_eq!(reports[1].error, None);
+        assert_eq!(
+            reports[2].error.as_deref(),
+            Some(r#"wrong answer "8", expected "9""#)
+        );
+    }
+
+    struct Panics {}
+
+    impl Problem for Panics {
+        type Input = usize;
+        type Extra = ();
+
+        const DAY: usize = 24;
+
+        fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+            Ok(s.parse().unwrap())
+        }
+
+        fn part_1(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            panic!("We never found a winner, bad input");
+        }
+
+        fn part_2(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            (*input).into()
+        }
+    }
+
+    #[test]
+    fn isolates_panics() {
+        let reporter = Arc::new(Collect::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            ..Default::default()
+        };
+
+        assert!(!run_with_name::<Panics>(
+            "1",
+            true,
+            RunFor::Both,
+            (),
+            "3",
+            [None, None],
+            &options
+        ));
+        assert!(!run_with_name::<Panics>(
+            "2",
+            true,
+            RunFor::Both,
+            (),
+            "x",
+            [None, None],
+            &options
+        ));
+
+        let reports = reporter.0.lock().unwrap();
+        let errors = reports
+            .iter()
+            .map(|r| (r.name.as_str(), r.answer.clone(), r.error.is_some()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                ("24.1 Example 1", None, true),
+                ("24.2 Example 1", Some(Answer::Number(3)), false),
+                ("24.1 Example 2", None, true),
+                ("24.2 Example 2", None, true),
+            ]
+        );
+        assert!(reports[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .ends_with("We never found a winner, bad input"));
+        assert!(reports[2].error.as_ref().unwrap().contains("panicked at"));
+    }
+
+    #[test]
+    fn times_out_each_sample() {
+        let reporter = Arc::new(Collect::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            bench: Some(BenchOptions {
+                warmup: 1,
+                samples: None,
+                budget: Duration::from_millis(300),
+            }),
+            timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+
+        assert!(run_with_name::<Slow>(
+            " ",
+            false,
+            RunFor::Part1,
+            (),
+            "5",
+            [None, None],
+            &options
+        ));
+        let reports = reporter.0.lock().unwrap();
+        assert_eq!(reports[0].error, None);
+    }
+
+    struct Unsolved {}
+
+    impl Problem for Unsolved {
+        type Input = ();
+        type Extra = ();
+
+        const DAY: usize = 22;
+
+        fn parse(_s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            Answer::NoAnswer
+        }
+    }
+
+    #[test]
+    fn known_answers_need_a_value() {
+        let dir = TempDir::new("known");
+        let path = dir.join("answers.toml");
+        let answers = Answers::load(&path).unwrap();
+        for part in [1, 2] {
+            answers.record(22, part, "input", "5").unwrap();
+        }
+
+        let reporter = Arc::new(Collect::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            answers: Some(Arc::new(answers)),
+            ..Default::default()
+        };
+        let solved = run_with_name::<Unsolved>(
+            " ",
+            false,
+            RunFor::Both,
+            (),
+            "input",
+            [None, None],
+            &options,
+        );
+        assert!(!solved);
+
+        let reports = reporter.0.lock().unwrap();
+        let errors = reports
+            .iter()
+            .map(|r| r.error.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                r#"expected "5", got nothing (no answer)"#,
+                r#"expected "5", got nothing (not implemented)"#
+            ]
+        );
+    }
+
+    #[test]
+    fn only_submits_unknown_answers() {
+        let server = TestServer::start(|_| (200, "<p>That's the right answer!</p>".to_string()));
+        let dir = TempDir::new("submit-known");
+        let answers = Answers::load(dir.join("answers.toml")).unwrap();
+        answers.record(25, 1, "5", "10").unwrap();
+        answers.record(25, 2, "6", "0").unwrap();
+
+        let options = RunOptions {
+            reporter: Arc::new(Collect::default()),
+            answers: Some(Arc::new(answers)),
+            submitter: Some(
+                Submitter::new("secret")
+                    .with_base_url(server.base_url())
+                    .with_history(dir.join("history"))
+                    .with_throttle(Duration::ZERO),
+            ),
+            ..Default::default()
+        };
+        for input in ["5", "6"] {
+            run_with_name::<Double>(" ", false, RunFor::Both, (), input, [None, None], &options);
+        }
+
+        // 5.1 is known to be right and 6.2 known to be wrong, so only 5.2 and 6.1 are sent
+        let answers = server
+            .requests()
+            .iter()
+            .map(|r| r.body.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(answers, ["level=2&answer=20", "level=1&answer=12"]);
+    }
+
+    struct Slow {}
+
+    impl Problem for Slow {
+        type Input = u64;
+        type Extra = ();
+
+        const DAY: usize = 23;
+
+        fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+            parse_at(s, s)
+        }
+
+        /// Takes the input in milliseconds
+        fn part_1(input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+            std::thread::sleep(Duration::from_millis(*input));
+            Answer::from(1)
+        }
+
+        /// Like part 1, except that 0 runs until it's cancelled
+        fn part_2(input: &Self::Input, state: &ProblemState<Self::Extra>) -> Answer {
+            while *input == 0 && !state.cancel.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            std::thread::sleep(Duration::from_millis(*input));
+            Answer::from(2)
+        }
+    }
+
+    #[test]
+    fn times_out_each_part_of_solve() {
+        let reporter = Arc::new(Collect::default());
+        let options = RunOptions {
+            reporter: reporter.clone(),
+            timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+
+        // both parts together take longer than the limit, but neither does on its own
+        assert!(run_with_name::<Slow>(
+            "1",
+            true,
+            RunFor::Both,
+            (),
+            "60",
+            [Some("1"), Some("2")],
+            &options
+        ));
+        assert!(!run_with_name::<Slow>(
+            "2",
+            true,
+            RunFor::Both,
+            (),
+            "0",
+            [Some("1"), Some("2")],
+            &options
+        ));
+
+        let reports = reporter.0.lock().unwrap();
+        let results = reports
+            .iter()
+            .map(|r| (r.name.as_str(), r.answer.clone(), r.error.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            [
+                ("23.1 Example 1", Some(Answer::Number(1)), None),
+                ("23.2 Example 1", Some(Answer::Number(2)), None),
+                ("23.1 Example 2", Some(Answer::Number(1)), None),
+                (
+                    "23.2 Example 2",
+                    None,
+                    Some("timed out after 0.100000000s".to_string())
+                ),
+            ]
+        );
+    }
+}
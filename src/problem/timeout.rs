@@ -0,0 +1,177 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::bench::seconds;
+
+/// How long a part gets to notice it was cancelled before we stop waiting for it
+pub static CANCEL_GRACE: Duration = Duration::from_secs(1);
+
+/// Set once a part has used up its time limit. Long running parts should check it and give up.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// When a runner's current lap started, so each lap can be given its own time limit
+#[derive(Debug, Clone)]
+pub struct LapTimer(Arc<Mutex<Instant>>);
+
+impl LapTimer {
+    pub fn new() -> LapTimer {
+        LapTimer(Arc::new(Mutex::new(Instant::now())))
+    }
+
+    /// Start a new lap, returning how long the last one took
+    pub fn lap(&self) -> Duration {
+        let mut started = self.0.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now - *started;
+        *started = now;
+        elapsed
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.0.lock().unwrap().elapsed()
+    }
+}
+
+impl Default for LapTimer {
+    fn default() -> Self {
+        LapTimer::new()
+    }
+}
+
+/// A part that didn't finish within its time limit
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedOut {
+    pub limit: Duration,
+    /// It ignored its cancellation and was left running in the background
+    pub abandoned: bool,
+}
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out after {}", seconds(self.limit))?;
+        if self.abandoned {
+            write!(f, ", it ignored cancellation and was left running")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `runner` on a watchdog thread, cancelling `cancel` once `limit` has passed. If the runner
+/// still hasn't finished after `CANCEL_GRACE` we give up waiting on it.
+pub fn with_timeout<T, F>(limit: Duration, cancel: &CancelToken, runner: F) -> Result<T, TimedOut>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    with_lap_timeout(limit, &LapTimer::new(), cancel, runner)
+}
+
+/// Like `with_timeout`, but `limit` applies to each lap of `timer` rather than the whole run, so
+/// a runner doing several things in turn can start a new lap for each
+pub fn with_lap_timeout<T, F>(
+    limit: Duration,
+    timer: &LapTimer,
+    cancel: &CancelToken,
+    runner: F,
+) -> Result<T, TimedOut>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // the receiver is gone if we already gave up on this part
+        let _ = sender.send(runner());
+    });
+
+    loop {
+        match receiver.recv_timeout(limit.saturating_sub(timer.elapsed())) {
+            Ok(result) => return Ok(result),
+            // a new lap started while we were waiting
+            Err(RecvTimeoutError::Timeout) if timer.elapsed() < limit => {}
+            Err(_) => break,
+        }
+    }
+
+    cancel.cancel();
+    let abandoned = receiver.recv_timeout(CANCEL_GRACE).is_err();
+    Err(TimedOut { limit, abandoned })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn times_out() {
+        let limit = Duration::from_millis(50);
+
+        let cancel = CancelToken::default();
+        assert_eq!(with_timeout(limit, &cancel, || 5), Ok(5));
+        assert!(!cancel.is_cancelled());
+
+        let cancelled = cancel.clone();
+        let result = with_timeout(limit, &cancel, move || {
+            while !cancelled.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        assert_eq!(
+            result,
+            Err(TimedOut {
+                limit,
+                abandoned: false
+            })
+        );
+        assert!(cancel.is_cancelled());
+
+        let result = with_timeout(limit, &CancelToken::default(), || {
+            thread::sleep(CANCEL_GRACE * 3)
+        });
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "timed out after 0.050000000s, it ignored cancellation and was left running"
+        );
+    }
+
+    #[test]
+    fn times_out_laps() {
+        let limit = Duration::from_millis(50);
+
+        // three laps that each fit in the limit, but not all together
+        let timer = LapTimer::new();
+        let lapping = timer.clone();
+        let result = with_lap_timeout(limit, &timer, &CancelToken::default(), move || {
+            for _ in 0..3 {
+                lapping.lap();
+                thread::sleep(limit / 2);
+            }
+            5
+        });
+        assert_eq!(result, Ok(5));
+
+        let cancel = CancelToken::default();
+        let (timer, cancelled) = (LapTimer::new(), cancel.clone());
+        let result = with_lap_timeout(limit, &timer, &cancel, move || {
+            while !cancelled.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        assert_eq!(result.unwrap_err().limit, limit);
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Mutex;
+
+use crate::report::{PartReport, Reporter, Timing};
+
+enum Event {
+    Parsed {
+        day: usize,
+        name: String,
+        timing: Timing,
+    },
+    Failed {
+        name: String,
+        error: String,
+        reports: Vec<PartReport>,
+    },
+    Part(Box<PartReport>),
+    Message(String),
+}
+
+/// Holds on to everything reported so it can be passed on later, letting days that run at the
+/// same time still be reported in order
+#[derive(Default)]
+pub struct Buffer {
+    events: Mutex<Vec<Event>>,
+}
+
+impl Buffer {
+    /// Pass everything reported so far on to `reporter`, emptying the buffer
+    pub fn replay(&self, reporter: &dyn Reporter) {
+        for event in self.events.lock().unwrap().drain(..) {
+            match event {
+                Event::Parsed { day, name, timing } => reporter.parsed(day, &name, &timing),
+                Event::Failed {
+                    name,
+                    error,
+                    reports,
+                } => reporter.failed(&name, &error, &reports),
+                Event::Part(report) => reporter.part(&report),
+                Event::Message(message) => reporter.message(&message),
+            }
+        }
+    }
+
+    fn push(&self, event: Event) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl Reporter for Buffer {
+    fn parsed(&self, day: usize, name: &str, timing: &Timing) {
+        self.push(Event::Parsed {
+            day,
+            name: name.to_string(),
+            timing: timing.clone(),
+        });
+    }
+
+    fn failed(&self, name: &str, error: &str, reports: &[PartReport]) {
+        self.push(Event::Failed {
+            name: name.to_string(),
+            error: error.to_string(),
+            reports: reports.to_vec(),
+        });
+    }
+
+    fn part(&self, report: &PartReport) {
+        self.push(Event::Part(Box::new(report.clone())));
+    }
+
+    fn message(&self, message: &str) {
+        self.push(Event::Message(message.to_string()));
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::IsTerminal;
+
+use crate::report::{PartReport, Reporter, Timing};
+
+/// Writes human readable results to stdout, colored per day unless `NO_COLOR` is set or stdout
+/// isn't a terminal
+pub struct ConsoleReporter {
+    color: bool,
+}
+
+impl ConsoleReporter {
+    pub fn new() -> ConsoleReporter {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+        ConsoleReporter {
+            color: !no_color && std::io::stdout().is_terminal(),
+        }
+    }
+
+    fn label(&self, day: usize, name: &str) -> String {
+        if self.color {
+            format!("\u{001B}[3{}m{}:\u{001B}[0m", (day % 5) + 2, name)
+        } else {
+            format!("{}:", name)
+        }
+    }
+}
+
+impl Default for ConsoleReporter {
+    fn default() -> Self {
+        ConsoleReporter::new()
+    }
+}
+
+impl Reporter for ConsoleReporter {
+    fn parsed(&self, day: usize, name: &str, timing: &Timing) {
+        if timing.stats.is_some() || timing.allocations.is_some() {
+            println!(
+                "{} {}",
+                self.label(day, &format!("{} parse", name)),
+                time(timing)
+            );
+        }
+    }
+
+    fn failed(&self, name: &str, error: &str, _reports: &[PartReport]) {
+        eprintln!("{}:\n{}", name, error);
+    }
+
+    fn part(&self, report: &PartReport) {
+        if let Some(error) = &report.error {
+            eprintln!("{}: {}", report.name, error);
+        } else if let (Some(answer), Some(solve_time)) = (&report.answer, &report.solve_time) {
+            let label = self.label(report.day, &report.name);
+            let notes = report
+                .check
+                .iter()
+                .map(|check| check.to_string())
+                .chain(report.baseline.iter().map(|baseline| baseline.to_string()))
+                .collect::<Vec<_>>();
+            let suffix = if notes.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", notes.join(", "))
+            };
+            println!("{} {}{}", label, time(solve_time), suffix);
+
+            println!("{}", answer);
+        }
+    }
+
+    fn message(&self, message: &str) {
+        println!("{}", message);
+    }
+}
+
+/// The benchmark stats or the single run's time, followed by any allocation counts
+fn time(timing: &Timing) -> String {
+    let time = match &timing.stats {
+        Some(stats) => stats.to_string(),
+        None => format!(
+            "{:2}.{:09}s",
+            timing.elapsed.as_secs(),
+            timing.elapsed.subsec_nanos()
+        ),
+    };
+
+    match &timing.allocations {
+        Some(allocations) => format!("{} [{}]", time, allocations),
+        None => time,
+    }
+}
@@ -0,0 +1,10 @@
+use crate::report::{PartReport, Reporter};
+
+/// Writes a JSON object per line for every part, see `PartReport::to_json`
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn part(&self, report: &PartReport) {
+        println!("{}", report.to_json());
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::report::{PartReport, Reporter};
+
+/// Collects every part and writes a JUnit XML document on `finish`, with a test suite per day
+#[derive(Default)]
+pub struct JunitReporter {
+    reports: Mutex<Vec<PartReport>>,
+}
+
+impl JunitReporter {
+    pub fn render(&self) -> String {
+        let reports = self.reports.lock().unwrap();
+        let mut days: BTreeMap<usize, Vec<&PartReport>> = BTreeMap::new();
+        for report in reports.iter() {
+            days.entry(report.day).or_default().push(report);
+        }
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+        for (day, reports) in days {
+            let failures = reports.iter().filter(|r| is_failure(r)).count();
+            let errors = reports.iter().filter(|r| r.error.is_some()).count() - failures;
+            let time = reports.iter().map(|r| solve_seconds(r)).sum::<f64>();
+            xml.push_str(&format!(
+                "  <testsuite name=\"day {}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\">\n",
+                day,
+                reports.len(),
+                failures,
+                errors,
+                time
+            ));
+
+            for report in reports {
+                xml.push_str(&format!(
+                    "    <testcase classname=\"day{}\" name=\"{}\" time=\"{:.6}\"",
+                    day,
+                    escape(report.name.trim_end()),
+                    solve_seconds(report)
+                ));
+                match (&report.error, &report.answer) {
+                    (Some(error), Some(_)) => xml.push_str(&format!(
+                        ">\n      <failure message=\"{}\"/>\n    </testcase>\n",
+                        escape(error)
+                    )),
+                    (Some(error), None) => xml.push_str(&format!(
+                        ">\n      <error message=\"{}\"/>\n    </testcase>\n",
+                        escape(error)
+                    )),
+                    (None, Some(answer)) => xml.push_str(&format!(
+                        ">\n      <system-out>{}</system-out>\n    </testcase>\n",
+                        escape(&answer.to_string())
+                    )),
+                    (None, None) => xml.push_str("/>\n"),
+                }
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>");
+
+        xml
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn part(&self, report: &PartReport) {
+        self.reports.lock().unwrap().push(report.clone());
+    }
+
+    fn finish(&self) {
+        println!("{}", self.render());
+    }
+}
+
+/// A part that answered, but not what was expected. Any other error, like a panic, a timeout or an
+/// input that didn't parse, means the part never answered at all.
+fn is_failure(report: &PartReport) -> bool {
+    report.error.is_some() && report.answer.is_some()
+}
+
+fn solve_seconds(report: &PartReport) -> f64 {
+    report
+        .solve_time
+        .as_ref()
+        .map_or(0.0, |timing| timing.elapsed.as_secs_f64())
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::report::test::report;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let reporter = JunitReporter::default();
+        reporter.part(&report(1, Some("<5>"), None));
+        reporter.part(&report(2, None, Some("input \"missing\"")));
+        reporter.part(&report(
+            1,
+            Some("4"),
+            Some("wrong answer \"4\", expected \"5\""),
+        ));
+
+        assert_eq!(
+            reporter.render(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="day 5" tests="3" failures="1" errors="1" time="0.000002">
+    <testcase classname="day5" name="5.1 Example 1" time="0.000002">
+      <system-out>&lt;5&gt;</system-out>
+    </testcase>
+    <testcase classname="day5" name="5.2 Example 1" time="0.000000">
+      <error message="input &quot;missing&quot;"/>
+    </testcase>
+    <testcase classname="day5" name="5.1 Example 1" time="0.000000">
+      <failure message="wrong answer &quot;4&quot;, expected &quot;5&quot;"/>
+    </testcase>
+  </testsuite>
+</testsuites>"#
+        );
+    }
+}
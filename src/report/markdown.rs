@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use crate::bench::seconds;
+use crate::report::{PartReport, Reporter, Timing};
+
+/// Collects every part and writes a Markdown table on `finish`
+#[derive(Default)]
+pub struct MarkdownReporter {
+    reports: Mutex<Vec<PartReport>>,
+}
+
+impl MarkdownReporter {
+    pub fn render(&self) -> String {
+        let mut table = String::from(
+            "| Day | Part | Input | Answer | Parse | Solve |\n| ---: | ---: | --- | --- | ---: | ---: |\n",
+        );
+
+        for report in self.reports.lock().unwrap().iter() {
+            let input = report
+                .name
+                .split_once(' ')
+                .map_or("", |(_, input)| input.trim());
+            let answer = match (&report.error, &report.answer) {
+                (Some(error), _) => format!("**error:** {}", cell(error)),
+                (None, Some(answer)) => match &report.check {
+                    Some(check) => format!("`{}` ({})", cell(&answer.to_string()), check),
+                    None => format!("`{}`", cell(&answer.to_string())),
+                },
+                (None, None) => String::new(),
+            };
+            let time = |timing: &Option<Timing>| {
+                timing
+                    .as_ref()
+                    .map_or(String::new(), |timing| seconds(timing.elapsed))
+            };
+
+            table.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                report.day,
+                report.part,
+                input,
+                answer,
+                time(&report.parse_time),
+                match &report.baseline {
+                    Some(baseline) => format!("{} ({})", time(&report.solve_time), baseline),
+                    None => time(&report.solve_time),
+                }
+            ));
+        }
+
+        table
+    }
+}
+
+impl Reporter for MarkdownReporter {
+    fn part(&self, report: &PartReport) {
+        self.reports.lock().unwrap().push(report.clone());
+    }
+
+    fn finish(&self) {
+        print!("{}", self.render());
+    }
+}
+
+/// Keep multi-line answers, like rendered grids, inside a single table cell
+fn cell(s: &str) -> String {
+    s.trim_end().replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::report::test::report;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let reporter = MarkdownReporter::default();
+        reporter.part(&report(1, Some("#.\n.#\n"), None));
+        reporter.part(&report(2, None, Some("a | b")));
+
+        assert_eq!(
+            reporter.render(),
+            r#"| Day | Part | Input | Answer | Parse | Solve |
+| ---: | ---: | --- | --- | ---: | ---: |
+| 5 | 1 | Example 1 | `#.<br>.#` | 0.000003000s | 0.000001500s |
+| 5 | 2 | Example 1 | **error:** a \| b | 0.000003000s |  |
+"#
+        );
+    }
+}
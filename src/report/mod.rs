@@ -0,0 +1,184 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::answers::Check;
+use crate::bench::baseline::Comparison;
+use crate::bench::Stats;
+use crate::problem::alloc::AllocStats;
+use crate::problem::Answer;
+use crate::report::console::ConsoleReporter;
+use crate::report::json::JsonReporter;
+use crate::report::junit::JunitReporter;
+use crate::report::markdown::MarkdownReporter;
+
+pub mod buffer;
+pub mod console;
+pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod summary;
+
+/// Receives the results of a run as they happen
+pub trait Reporter: Send + Sync {
+    /// A problem's input was parsed, called before any of its parts are reported
+    fn parsed(&self, _day: usize, _name: &str, _timing: &Timing) {}
+
+    /// A problem couldn't be run at all, `reports` hold the error for each part we skipped
+    fn failed(&self, _name: &str, _error: &str, reports: &[PartReport]) {
+        for report in reports {
+            self.part(report);
+        }
+    }
+
+    fn part(&self, report: &PartReport);
+
+    /// Anything else worth telling the user about, like submission results
+    fn message(&self, message: &str) {
+        eprintln!("{}", message);
+    }
+
+    /// Called once everything has run, for reporters that write a single document
+    fn finish(&self) {}
+}
+
+/// How results are written to stdout
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Format {
+    /// Human readable text, colored when writing to a terminal
+    #[default]
+    Console,
+    /// One JSON object per line for each part that was run
+    Json,
+    /// A JUnit XML document with a test suite per day
+    Junit,
+    /// A Markdown table with a row per part
+    Markdown,
+}
+
+impl Format {
+    pub fn reporter(self) -> Arc<dyn Reporter> {
+        match self {
+            Format::Console => Arc::new(ConsoleReporter::new()),
+            Format::Json => Arc::new(JsonReporter),
+            Format::Junit => Arc::new(JunitReporter::default()),
+            Format::Markdown => Arc::new(MarkdownReporter::default()),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "console" => Ok(Format::Console),
+            "json" => Ok(Format::Json),
+            "junit" => Ok(Format::Junit),
+            "markdown" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "expected console, json, junit or markdown, found: {}",
+                s
+            )),
+        }
+    }
+}
+
+/// How long a step took, with the full statistics if it was benchmarked
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    /// The single run's time, or the median when benchmarking
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// What a single run allocated, when built with the `alloc-stats` feature
+    pub allocations: Option<AllocStats>,
+}
+
+/// The outcome of running a single part of a problem
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: usize,
+    pub part: usize,
+    pub name: String,
+    pub is_example: bool,
+    /// What the part answered, if it ran at all
+    pub answer: Option<Answer>,
+    pub parse_time: Option<Timing>,
+    pub solve_time: Option<Timing>,
+    pub error: Option<String>,
+    /// How the answer compares to the known answers, for real inputs
+    pub check: Option<Check>,
+    /// How the solve time compares to the baseline, for real inputs
+    pub baseline: Option<Comparison>,
+}
+
+impl PartReport {
+    pub fn to_json(&self) -> serde_json::Value {
+        let nanos = |timing: &Option<Timing>| timing.as_ref().map(|t| t.elapsed.as_nanos() as u64);
+
+        let allocations = |timing: &Option<Timing>| {
+            timing.as_ref().and_then(|t| t.allocations).map(|a| {
+                json!({
+                    "allocations": a.allocations,
+                    "bytes": a.bytes,
+                    "peak": a.peak,
+                })
+            })
+        };
+
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "name": self.name.trim_end(),
+            "example": self.is_example,
+            "answer": self.answer.as_ref().and_then(Answer::value),
+            "kind": self.answer.as_ref().map(Answer::kind),
+            "parse_ns": nanos(&self.parse_time),
+            "solve_ns": nanos(&self.solve_time),
+            "parse_allocations": allocations(&self.parse_time),
+            "solve_allocations": allocations(&self.solve_time),
+            "error": self.error,
+            "check": self.check.as_ref().map(|check| check.to_string()),
+            "baseline_ns": self.baseline.as_ref().map(|b| b.baseline.as_nanos() as u64),
+            "regressed": self.baseline.as_ref().map(|b| b.regressed),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    pub fn report(part: usize, answer: Option<&str>, error: Option<&str>) -> PartReport {
+        PartReport {
+            day: 5,
+            part,
+            name: format!("5.{} Example 1", part),
+            is_example: true,
+            answer: answer.map(Answer::from),
+            parse_time: Some(Timing {
+                elapsed: Duration::from_micros(3),
+                stats: None,
+                allocations: None,
+            }),
+            solve_time: error.is_none().then_some(Timing {
+                elapsed: Duration::from_nanos(1500),
+                stats: None,
+                allocations: None,
+            }),
+            error: error.map(String::from),
+            check: None,
+            baseline: None,
+        }
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            report(2, Some("12"), None).to_json().to_string(),
+            r#"{"answer":"12","baseline_ns":null,"check":null,"day":5,"error":null,"example":true,"kind":"text","name":"5.2 Example 1","parse_allocations":null,"parse_ns":3000,"part":2,"regressed":null,"solve_allocations":null,"solve_ns":1500}"#
+        );
+    }
+}
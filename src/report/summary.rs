@@ -0,0 +1,145 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::problem::Answer;
+use crate::report::{PartReport, Reporter, Timing};
+
+/// Passes everything through to another reporter while keeping count of the parts that passed
+/// and failed, and which got slower than the baseline, so we can summarise the run at the end
+pub struct Summary {
+    inner: Arc<dyn Reporter>,
+    passed: AtomicUsize,
+    not_implemented: AtomicUsize,
+    failures: Mutex<Vec<String>>,
+    regressions: Mutex<Vec<String>>,
+}
+
+impl Summary {
+    pub fn new(inner: Arc<dyn Reporter>) -> Summary {
+        Summary {
+            inner,
+            passed: AtomicUsize::new(0),
+            not_implemented: AtomicUsize::new(0),
+            failures: Mutex::new(vec![]),
+            regressions: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn passed(&self) -> usize {
+        self.passed.load(Ordering::SeqCst)
+    }
+
+    /// The names of every part that failed, in the order they were reported
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.lock().unwrap().clone()
+    }
+
+    /// The names of every part that regressed against the baseline, in the order they were reported
+    pub fn regressions(&self) -> Vec<String> {
+        self.regressions.lock().unwrap().clone()
+    }
+
+    fn record(&self, report: &PartReport) {
+        if report.baseline.as_ref().is_some_and(|c| c.regressed) {
+            self.regressions
+                .lock()
+                .unwrap()
+                .push(report.name.trim_end().to_string());
+        }
+
+        if report.error.is_some() {
+            self.failures
+                .lock()
+                .unwrap()
+                .push(report.name.trim_end().to_string());
+        } else if report.answer == Some(Answer::NotImplemented) {
+            self.not_implemented.fetch_add(1, Ordering::SeqCst);
+        } else {
+            self.passed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let failures = self.failures();
+        write!(f, "{} passed, {} failed", self.passed(), failures.len())?;
+        let not_implemented = self.not_implemented.load(Ordering::SeqCst);
+        if not_implemented > 0 {
+            write!(f, ", {} not implemented", not_implemented)?;
+        }
+        if !failures.is_empty() {
+            write!(f, ": {}", failures.join(", "))?;
+        }
+        let regressions = self.regressions();
+        if !regressions.is_empty() {
+            write!(f, "; regressed: {}", regressions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Reporter for Summary {
+    fn parsed(&self, day: usize, name: &str, timing: &Timing) {
+        self.inner.parsed(day, name, timing);
+    }
+
+    fn failed(&self, name: &str, error: &str, reports: &[PartReport]) {
+        for report in reports {
+            self.record(report);
+        }
+        self.inner.failed(name, error, reports);
+    }
+
+    fn part(&self, report: &PartReport) {
+        self.record(report);
+        self.inner.part(report);
+    }
+
+    fn message(&self, message: &str) {
+        self.inner.message(message);
+    }
+
+    fn finish(&self) {
+        self.inner.finish();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::bench::baseline::Comparison;
+    use crate::report::json::JsonReporter;
+    use crate::report::test::report;
+
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let summary = Summary::new(Arc::new(JsonReporter));
+        summary.part(&report(1, Some("7"), None));
+        summary.failed(
+            "5 Example 1",
+            "bad input",
+            &[report(2, None, Some("bad input"))],
+        );
+
+        assert_eq!(summary.passed(), 1);
+        assert_eq!(summary.to_string(), "1 passed, 1 failed: 5.2 Example 1");
+
+        let mut slower = report(1, Some("7"), None);
+        slower.baseline = Some(Comparison {
+            baseline: Duration::from_micros(1),
+            median: Duration::from_micros(2),
+            regressed: true,
+        });
+        summary.part(&slower);
+        assert_eq!(summary.regressions(), ["5.1 Example 1"]);
+        assert_eq!(
+            summary.to_string(),
+            "2 passed, 1 failed: 5.2 Example 1; regressed: 5.1 Example 1"
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+use crate::input::DEFAULT_INPUTS_DIR;
+
+pub static DEFAULT_DAYS_DIR: &str = "src/days";
+
+/// The new day's module, `Fourteen` and `14` are replaced with its name and number
+const TEMPLATE: &str = include_str!("scaffold/template.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    BadDay(usize),
+    /// The day already has a module or is already registered
+    Exists(String),
+    /// We couldn't find where to register the day in the days module
+    Unregistered(String),
+    Io {
+        path: String,
+        error: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::BadDay(day) => write!(f, "Day {} isn't between 1 and 25", day),
+            ScaffoldError::Exists(what) => write!(f, "{} already exists", what),
+            ScaffoldError::Unregistered(why) => write!(f, "Couldn't register the day: {}", why),
+            ScaffoldError::Io { path, error } => write!(f, "Couldn't write {}: {}", path, error),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Generates new days from a template and registers them with `register_days!`
+pub struct Scaffold {
+    days_dir: PathBuf,
+    inputs_dir: PathBuf,
+}
+
+impl Scaffold {
+    /// `days_dir` holds the `dayN.rs` modules and the `mod.rs` that registers them
+    pub fn new<P: Into<PathBuf>>(days_dir: P) -> Scaffold {
+        Scaffold {
+            days_dir: days_dir.into(),
+            inputs_dir: PathBuf::from(DEFAULT_INPUTS_DIR),
+        }
+    }
+
+    pub fn with_inputs_dir<P: Into<PathBuf>>(mut self, inputs_dir: P) -> Scaffold {
+        self.inputs_dir = inputs_dir.into();
+        self
+    }
+
+    /// Create `day`'s module, register it and add an empty input for it if there isn't one yet.
+    /// Nothing is written if the day already exists. Returns the files that were written.
+    pub fn create(&self, day: usize) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let name = day_name(day).ok_or(ScaffoldError::BadDay(day))?;
+        let module = format!("day{}", day);
+
+        let module_path = self.days_dir.join(format!("{}.rs", module));
+        if module_path.exists() {
+            return Err(ScaffoldError::Exists(module_path.display().to_string()));
+        }
+
+        let mod_path = self.days_dir.join("mod.rs");
+        let days_mod = fs::read_to_string(&mod_path).map_err(|error| ScaffoldError::Io {
+            path: mod_path.display().to_string(),
+            error,
+        })?;
+        let days_mod = register(&days_mod, day, &module, &name)?;
+
+        let source = TEMPLATE
+            .replace("Fourteen", &name)
+            .replace("DAY: usize = 14", &format!("DAY: usize = {}", day));
+        let mut written = vec![];
+        write(&module_path, &source, &mut written)?;
+        if let Err(e) = write(&mod_path, &days_mod, &mut written) {
+            // an unregistered module would stop us from trying again
+            let _ = fs::remove_file(&module_path);
+            return Err(e);
+        }
+
+        let input_path = self.inputs_dir.join(format!("{}.txt", day));
+        if !input_path.exists() {
+            // the downloader fills in empty inputs
+            write(&input_path, "", &mut written)?;
+        }
+
+        Ok(written)
+    }
+}
+
+fn write(path: &Path, contents: &str, written: &mut Vec<PathBuf>) -> Result<(), ScaffoldError> {
+    let io_error = |error| ScaffoldError::Io {
+        path: path.display().to_string(),
+        error,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)?;
+    written.push(path.to_path_buf());
+
+    Ok(())
+}
+
+/// Add `module` to the days module's `pub mod`s, and its problem to `register_days!`, keeping
+/// both in the order rustfmt and `check_days` expect
+fn register(days_mod: &str, day: usize, module: &str, name: &str) -> Result<String, ScaffoldError> {
+    let mut lines = days_mod.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let mod_line = format!("pub mod {};", module);
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(i, line)| (i, line.clone()))
+        .collect::<Vec<_>>();
+    if mods.iter().any(|(_, line)| line == &mod_line) {
+        return Err(ScaffoldError::Exists(format!("mod {}", module)));
+    }
+    let (last_mod, _) = mods
+        .last()
+        .ok_or_else(|| ScaffoldError::Unregistered("no `pub mod day` lines".to_string()))?;
+    let mod_index = mods
+        .iter()
+        .find(|(_, line)| line.trim_start_matches("pub mod ").trim_end_matches(';') > module)
+        .map_or(last_mod + 1, |(i, _)| *i);
+    lines.insert(mod_index, mod_line);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("register_days!("))
+        .ok_or_else(|| ScaffoldError::Unregistered("no register_days!(...)".to_string()))?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.starts_with(");"))
+        .map(|i| start + i)
+        .ok_or_else(|| ScaffoldError::Unregistered("register_days! isn't closed".to_string()))?;
+    let mut register_index = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let registered = line
+            .trim()
+            .strip_prefix("day")
+            .and_then(|rest| rest.split_once("::"))
+            .and_then(|(number, _)| number.parse::<usize>().ok());
+        match registered {
+            Some(registered) if registered == day => {
+                return Err(ScaffoldError::Exists(format!("Day {}", day)));
+            }
+            Some(registered) if registered > day => {
+                register_index = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    lines.insert(register_index, format!("    {}::{},", module, name));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The name of a day's problem, like `Thirteen`
+fn day_name(day: usize) -> Option<String> {
+    const NAMES: [&str; 20] = [
+        "One",
+        "Two",
+        "Three",
+        "Four",
+        "Five",
+        "Six",
+        "Seven",
+        "Eight",
+        "Nine",
+        "Ten",
+        "Eleven",
+        "Twelve",
+        "Thirteen",
+        "Fourteen",
+        "Fifteen",
+        "Sixteen",
+        "Seventeen",
+        "Eighteen",
+        "Nineteen",
+        "Twenty",
+    ];
+
+    match day {
+        1..=20 => Some(NAMES[day - 1].to_string()),
+        21..=25 => Some(format!("Twenty{}", NAMES[day - 21])),
+        _ => None,
+    }
+}
+
+/// The template, built with the tests so it has to compile cleanly
+#[cfg(test)]
+mod template;
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::problem::RunOptions;
+    use crate::report::{PartReport, Reporter};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn creates_and_registers() {
+        let dir = TempDir::new("scaffold");
+        let days_dir = dir.join("days");
+        fs::create_dir_all(&days_dir).unwrap();
+        fs::write(days_dir.join("mod.rs"), include_str!("days/mod.rs")).unwrap();
+        let scaffold = Scaffold::new(&days_dir).with_inputs_dir(dir.join("inputs"));
+
+        let written = scaffold.create(11).unwrap();
+        assert_eq!(
+            written,
+            [
+                days_dir.join("day11.rs"),
+                days_dir.join("mod.rs"),
+                dir.join("inputs/11.txt")
+            ]
+        );
+        let module = fs::read_to_string(days_dir.join("day11.rs")).unwrap();
+        assert!(module.contains("pub(crate) struct Eleven {}"));
+        assert!(module.contains("const DAY: usize = 11;"));
+        assert!(module.contains("problem_tests!(Eleven, ();"));
+
+        let days_mod = fs::read_to_string(days_dir.join("mod.rs")).unwrap();
+        assert!(days_mod.contains("pub mod day10;\npub mod day11;\npub mod day13;\n"));
+        assert!(days_mod.contains("    day10::Ten,\n    day11::Eleven,\n    day13::Thirteen,\n"));
+
+        assert!(matches!(scaffold.create(11), Err(ScaffoldError::Exists(_))));
+        // registered, even though its module isn't in this directory
+        assert!(matches!(scaffold.create(4), Err(ScaffoldError::Exists(_))));
+        assert!(matches!(
+            scaffold.create(26),
+            Err(ScaffoldError::BadDay(26))
+        ));
+        assert_eq!(
+            fs::read_to_string(days_dir.join("mod.rs")).unwrap(),
+            days_mod
+        );
+
+        fs::write(dir.join("inputs/12.txt"), "1\n2\n").unwrap();
+        assert_eq!(scaffold.create(12).unwrap().len(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("inputs/12.txt")).unwrap(),
+            "1\n2\n"
+        );
+    }
+
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<String>>);
+
+    impl Reporter for Collect {
+        fn part(&self, report: &PartReport) {
+            self.0.lock().unwrap().push(report.name.clone());
+        }
+    }
+
+    #[test]
+    fn template_is_skipped() {
+        let reporter = Arc::new(Collect::default());
+        template::run(&RunOptions {
+            reporter: reporter.clone(),
+            ..Default::default()
+        });
+        assert!(reporter.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(day_name(7).unwrap(), "Seven");
+        assert_eq!(day_name(25).unwrap(), "TwentyFive");
+        assert_eq!(day_name(0), None);
+    }
+}
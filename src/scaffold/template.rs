@@ -0,0 +1,44 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{self, Answer, Example, Problem, ProblemState, RunFor, RunOptions};
+
+pub(crate) struct Fourteen {}
+
+impl Problem for Fourteen {
+    type Input = Vec<String>;
+    type Extra = ();
+
+    const DAY: usize = 14;
+    // the parts that have been solved, the day isn't run until there are some
+    const PARTS: RunFor = RunFor::Neither;
+
+    fn parse(s: &str, _state: &ProblemState<Self::Extra>) -> Result<Self::Input, ParseError> {
+        parse::each_line(s, |line| Ok(line.to_string()))
+    }
+
+    fn part_1(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::NotImplemented
+    }
+
+    fn part_2(_input: &Self::Input, _state: &ProblemState<Self::Extra>) -> Answer {
+        Answer::NotImplemented
+    }
+
+    fn examples() -> Vec<Example<Self::Extra>> {
+        vec![Example::new(r#""#, "", "")]
+    }
+}
+
+pub fn run(options: &RunOptions) {
+    problem::run::<Fourteen>(options, ());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_tests;
+
+    problem_tests!(Fourteen, ();
+        // remove the #[ignore] once the example and its answers are filled in
+        #[ignore] example_1[part_1, part_2];
+    );
+}
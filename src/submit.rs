@@ -0,0 +1,411 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, fs, io};
+
+use lazy_static::lazy_static;
+use log::info;
+use regex::Regex;
+
+use crate::input::download::{DEFAULT_BASE_URL, USER_AGENT};
+
+pub static DEFAULT_HISTORY_FILE: &str = "submissions.txt";
+
+/// How long to wait after a wrong answer before we're willing to post another one for the same day
+pub static DEFAULT_THROTTLE: Duration = Duration::from_secs(60);
+
+/// What the server told us about a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// We submitted too recently and have to wait this long
+    Wait(Duration),
+    /// The part was already solved, or it isn't unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    fn parse_response(body: &str) -> Verdict {
+        lazy_static! {
+            static ref WAIT_RE: Regex = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+        }
+
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if let Some(wait) = WAIT_RE.captures(body) {
+            let minutes = wait.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            let seconds = wait[2].parse::<u64>().unwrap_or(0);
+            Verdict::Wait(Duration::from_secs(minutes * 60 + seconds))
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::Wait(DEFAULT_THROTTLE)
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn encode(&self) -> String {
+        match self {
+            Verdict::Correct => "correct".into(),
+            Verdict::Wrong => "wrong".into(),
+            Verdict::TooHigh => "too_high".into(),
+            Verdict::TooLow => "too_low".into(),
+            Verdict::Wait(duration) => format!("wait:{}", duration.as_secs()),
+            Verdict::WrongLevel => "wrong_level".into(),
+            Verdict::Unknown => "unknown".into(),
+        }
+    }
+
+    fn decode(s: &str) -> Option<Verdict> {
+        match s {
+            "correct" => Some(Verdict::Correct),
+            "wrong" => Some(Verdict::Wrong),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong_level" => Some(Verdict::WrongLevel),
+            "unknown" => Some(Verdict::Unknown),
+            _ => s
+                .strip_prefix("wait:")
+                .and_then(|seconds| seconds.parse().ok())
+                .map(|seconds| Verdict::Wait(Duration::from_secs(seconds))),
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wait(duration) => {
+                write!(f, "submitted too soon, wait {}s", duration.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "already solved or not unlocked yet"),
+            Verdict::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// A single submission, as saved in the history file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub day: usize,
+    pub part: usize,
+    /// Seconds since the unix epoch
+    pub time: u64,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    AlreadyCorrect(String),
+    KnownWrong(Verdict),
+    OutOfRange {
+        above: Option<i128>,
+        below: Option<i128>,
+    },
+    Throttled(Duration),
+    BadAnswer(String),
+    Http(String),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::AlreadyCorrect(answer) => write!(f, "already solved with {}", answer),
+            SubmitError::KnownWrong(verdict) => {
+                write!(f, "not submitted, this answer was {}", verdict)
+            }
+            SubmitError::OutOfRange { above, below } => {
+                write!(f, "not submitted, the answer must be")?;
+                if let Some(above) = above {
+                    write!(f, " above {}", above)?;
+                }
+                if let Some(below) = below {
+                    write!(f, " below {}", below)?;
+                }
+                Ok(())
+            }
+            SubmitError::Throttled(remaining) => {
+                write!(f, "not submitted, wait another {}s", remaining.as_secs())
+            }
+            SubmitError::BadAnswer(message) => write!(f, "not submitted, {}", message),
+            SubmitError::Http(message) => write!(f, "couldn't submit: {}", message),
+            SubmitError::Io(error) => write!(f, "couldn't update the history: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Posts answers to `<base_url>/day/<day>/answer`, keeping a history of every attempt so we never
+/// repeat a known wrong answer
+#[derive(Clone, PartialEq)]
+pub struct Submitter {
+    base_url: String,
+    session: String,
+    history_path: PathBuf,
+    throttle: Duration,
+}
+
+impl Submitter {
+    pub fn new<S: Into<String>>(session: S) -> Submitter {
+        Submitter {
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+            history_path: PathBuf::from(DEFAULT_HISTORY_FILE),
+            throttle: DEFAULT_THROTTLE,
+        }
+    }
+
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> Submitter {
+        self.base_url = base_url.into().trim_end_matches('/').into();
+        self
+    }
+
+    pub fn with_history<P: Into<PathBuf>>(mut self, history_path: P) -> Submitter {
+        self.history_path = history_path.into();
+        self
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Submitter {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn history(&self) -> Result<Vec<Attempt>, SubmitError> {
+        let history = match fs::read_to_string(&self.history_path) {
+            Ok(history) => history,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(SubmitError::Io(e)),
+        };
+
+        Ok(history
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(5, '\t');
+                Some(Attempt {
+                    day: fields.next()?.parse().ok()?,
+                    part: fields.next()?.parse().ok()?,
+                    time: fields.next()?.parse().ok()?,
+                    verdict: Verdict::decode(fields.next()?)?,
+                    answer: fields.next()?.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    /// Check our history to see if `answer` is worth submitting
+    pub fn check(&self, day: usize, part: usize, answer: &str) -> Result<(), SubmitError> {
+        if answer.is_empty() || answer.contains(['\n', '\t']) {
+            return Err(SubmitError::BadAnswer(format!(
+                "answers must be a single line: {:?}",
+                answer
+            )));
+        }
+
+        let history = self.history()?;
+        let attempts = history
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part);
+
+        let numeric_answer = answer.parse::<i128>().ok();
+        let mut above = None;
+        let mut below = None;
+        for attempt in attempts {
+            match &attempt.verdict {
+                Verdict::Correct => {
+                    return Err(SubmitError::AlreadyCorrect(attempt.answer.clone()))
+                }
+                verdict if verdict.is_wrong() && attempt.answer == answer => {
+                    return Err(SubmitError::KnownWrong(verdict.clone()))
+                }
+                _ => (),
+            }
+
+            if let Ok(guess) = attempt.answer.parse::<i128>() {
+                match attempt.verdict {
+                    Verdict::TooHigh => below = Some(below.map_or(guess, |b: i128| b.min(guess))),
+                    Verdict::TooLow => above = Some(above.map_or(guess, |a: i128| a.max(guess))),
+                    _ => (),
+                }
+            }
+        }
+
+        if let Some(answer) = numeric_answer {
+            if above.is_some_and(|a| answer <= a) || below.is_some_and(|b| answer >= b) {
+                return Err(SubmitError::OutOfRange { above, below });
+            }
+        }
+
+        // the server only makes us wait after a wrong answer, so throttle if that's how the last
+        // attempt for the day went, waiting longer if the server asked us to
+        if let Some(last) = history.iter().rev().find(|attempt| attempt.day == day) {
+            let wait = match &last.verdict {
+                Verdict::Wait(wait) => (*wait).max(self.throttle),
+                verdict if verdict.is_wrong() => self.throttle,
+                _ => Duration::ZERO,
+            };
+            let elapsed = Duration::from_secs(now().saturating_sub(last.time));
+            if elapsed < wait {
+                return Err(SubmitError::Throttled(wait - elapsed));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn submit(&self, day: usize, part: usize, answer: &str) -> Result<Verdict, SubmitError> {
+        self.check(day, part, answer)?;
+
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        info!("Submitting {} for {}.{} to {}", answer, day, part, url);
+
+        let body = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| SubmitError::Http(e.to_string()))?
+            .into_string()
+            .map_err(|e| SubmitError::Http(e.to_string()))?;
+        let verdict = Verdict::parse_response(&body);
+
+        let mut history = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.history_path)
+            .map_err(SubmitError::Io)?;
+        writeln!(
+            history,
+            "{}\t{}\t{}\t{}\t{}",
+            day,
+            part,
+            now(),
+            verdict.encode(),
+            answer
+        )
+        .map_err(SubmitError::Io)?;
+
+        Ok(verdict)
+    }
+}
+
+/// Leaves out the session, it's as good as a password
+impl Debug for Submitter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Submitter")
+            .field("base_url", &self.base_url)
+            .field("session", &"<redacted>")
+            .field("history_path", &self.history_path)
+            .field("throttle", &self.throttle)
+            .finish()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::temp_dir::TempDir;
+    use crate::test_server::TestServer;
+
+    use super::*;
+
+    #[test]
+    fn submit_and_track() {
+        let server = TestServer::start(|request| {
+            let body = if request.body.contains("answer=42") {
+                "<p>That's the right answer!</p>"
+            } else if request.body.contains("answer=50") {
+                "<p>That's not the right answer; your answer is too high.</p>"
+            } else if request.body.contains("answer=10") {
+                "<p>That's not the right answer; your answer is too low.</p>"
+            } else {
+                "<p>You gave an answer too recently; you have 1m 5s left to wait.</p>"
+            };
+            (200, body.to_string())
+        });
+
+        let dir = TempDir::new("history");
+        let history_path = dir.join("history");
+        let submitter = Submitter::new("secret")
+            .with_base_url(server.base_url())
+            .with_history(&history_path)
+            .with_throttle(Duration::ZERO);
+        assert!(!format!("{:?}", submitter).contains("secret"));
+
+        assert_eq!(submitter.submit(1, 1, "50").unwrap(), Verdict::TooHigh);
+        assert_eq!(submitter.submit(1, 1, "10").unwrap(), Verdict::TooLow);
+        assert!(matches!(
+            submitter.submit(1, 1, "50"),
+            Err(SubmitError::KnownWrong(Verdict::TooHigh))
+        ));
+        assert!(matches!(
+            submitter.submit(1, 1, "60"),
+            Err(SubmitError::OutOfRange {
+                above: Some(10),
+                below: Some(50)
+            })
+        ));
+        assert_eq!(submitter.submit(1, 1, "42").unwrap(), Verdict::Correct);
+        assert!(matches!(
+            submitter.submit(1, 1, "43"),
+            Err(SubmitError::AlreadyCorrect(_))
+        ));
+
+        assert_eq!(
+            submitter.submit(1, 2, "7").unwrap(),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert!(matches!(
+            submitter.submit(1, 2, "7"),
+            Err(SubmitError::Throttled(_))
+        ));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/day/1/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+        assert_eq!(requests[0].body, "level=1&answer=50");
+        assert_eq!(submitter.history().unwrap().len(), 4);
+
+        // a correct answer doesn't hold up the next part
+        let submitter = submitter.with_throttle(DEFAULT_THROTTLE);
+        assert_eq!(submitter.submit(2, 1, "42").unwrap(), Verdict::Correct);
+        assert_eq!(
+            submitter.submit(2, 2, "7").unwrap(),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert!(matches!(
+            submitter.submit(2, 2, "8"),
+            Err(SubmitError::Throttled(_))
+        ));
+    }
+}
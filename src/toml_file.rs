@@ -0,0 +1,118 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use toml::Table;
+
+#[derive(Debug)]
+pub enum TomlFileError {
+    Invalid {
+        kind: &'static str,
+        path: String,
+        message: String,
+    },
+    Io {
+        kind: &'static str,
+        path: String,
+        error: io::Error,
+    },
+}
+
+impl Display for TomlFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TomlFileError::Invalid {
+                kind,
+                path,
+                message,
+            } => write!(f, "invalid {} file {}: {}", kind, path, message),
+            TomlFileError::Io { kind, path, error } => {
+                write!(f, "couldn't access {} file {}: {}", kind, path, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TomlFileError {}
+
+/// A TOML file holding one of our stores, like the known answers or the benchmark baseline. A
+/// file that doesn't exist yet is an empty table.
+#[derive(Debug, Clone)]
+pub struct TomlFile {
+    /// What the file holds, for error messages like "invalid answers file"
+    kind: &'static str,
+    path: PathBuf,
+}
+
+impl TomlFile {
+    pub fn new<P: Into<PathBuf>>(kind: &'static str, path: P) -> TomlFile {
+        TomlFile {
+            kind,
+            path: path.into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<Table, TomlFileError> {
+        match fs::read_to_string(&self.path) {
+            Ok(s) => s.parse::<Table>().map_err(|e| self.invalid(e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Table::new()),
+            Err(error) => Err(self.io_error(error)),
+        }
+    }
+
+    /// Replace the file with `table`
+    pub fn save(&self, table: &Table) -> Result<(), TomlFileError> {
+        fs::write(&self.path, table.to_string()).map_err(|error| self.io_error(error))
+    }
+
+    /// An error for a file that's valid TOML, but not what we expected
+    pub fn invalid(&self, message: String) -> TomlFileError {
+        TomlFileError::Invalid {
+            kind: self.kind,
+            path: self.path.display().to_string(),
+            message,
+        }
+    }
+
+    fn io_error(&self, error: io::Error) -> TomlFileError {
+        TomlFileError::Io {
+            kind: self.kind,
+            path: self.path.display().to_string(),
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use toml::Value;
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn load_and_save() {
+        let dir = TempDir::new("toml");
+        let path = dir.join("answers.toml");
+        let file = TomlFile::new("answers", &path);
+        assert!(file.load().unwrap().is_empty());
+
+        let mut table = Table::new();
+        table.insert("8".to_string(), Value::Integer(365));
+        file.save(&table).unwrap();
+        assert_eq!(file.load().unwrap(), table);
+
+        fs::write(&path, "[8").unwrap();
+        let error = file.load().unwrap_err().to_string();
+        assert!(
+            error.starts_with(&format!("invalid answers file {}: ", path.display())),
+            "{}",
+            error
+        );
+    }
+}